@@ -0,0 +1,776 @@
+use std::{
+    collections::HashMap,
+    io::{self, Write},
+    path::{Path, PathBuf},
+    process::ExitCode,
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use clap::{Args, Subcommand};
+use iroh::SecretKey;
+use iroh_blobs::{ticket::BlobTicket, BlobFormat};
+use qrcode::{render::unicode::Dense1x2, QrCode};
+use rfd::FileHandle;
+use shared::{
+    app_events::AppEvent,
+    identity,
+    network::Network,
+    settings::{ConflictPolicy, Settings},
+    transfer::{next_transfer_id, Throughput, TransferState},
+    ui_events::UIEvent,
+    units::{format_duration, format_size, format_speed, parse_duration, parse_size},
+    websocket_messages::DEFAULT_ROOM,
+};
+
+use tokio::sync::{mpsc, oneshot};
+
+use crate::{
+    config,
+    iroh_node::{check_size, collect_files, Audience, IrohNode},
+    NativeNetwork,
+};
+
+/// Failed connection attempts we put up with before giving up, unlike the GUI which keeps trying.
+const MAX_CONNECT_ATTEMPTS: u32 = 5;
+
+#[derive(Subcommand)]
+pub enum Command {
+    #[command(flatten)]
+    Room(RoomCommand),
+    /// Share files straight from this machine without a server, prints a ticket for `fling get`
+    Share {
+        #[arg(required = true)]
+        paths: Vec<PathBuf>,
+        /// Only let the first machine to use the ticket download the files
+        #[arg(long)]
+        once: bool,
+        /// Stop sharing after this long, e.g. 10m or 2h
+        #[arg(long, value_parser = parse_duration)]
+        expires: Option<u64>,
+    },
+    /// Download files from a ticket printed by `fling share`
+    Get {
+        ticket: String,
+        #[command(flatten)]
+        save: SaveArgs,
+    },
+}
+
+/// Commands that go through the signaling server.
+#[derive(Subcommand)]
+pub enum RoomCommand {
+    /// Send files to someone in the room and wait for them to pick them up
+    Send {
+        #[arg(required = true)]
+        paths: Vec<PathBuf>,
+        /// Nickname of the recipient
+        #[arg(long)]
+        to: String,
+        #[command(flatten)]
+        session: SessionArgs,
+    },
+    /// Wait for an incoming transfer and download it
+    Receive {
+        /// Accept the first offer without asking
+        #[arg(long)]
+        auto_accept: bool,
+        #[command(flatten)]
+        save: SaveArgs,
+        #[command(flatten)]
+        session: SessionArgs,
+    },
+    /// List who's in the room
+    Peers {
+        #[command(flatten)]
+        session: SessionArgs,
+    },
+}
+
+impl RoomCommand {
+    fn session(&self) -> &SessionArgs {
+        match self {
+            RoomCommand::Send { session, .. }
+            | RoomCommand::Receive { session, .. }
+            | RoomCommand::Peers { session } => session,
+        }
+    }
+}
+
+#[derive(Args)]
+pub struct SessionArgs {
+    /// Room to join
+    #[arg(long, default_value = DEFAULT_ROOM)]
+    room: String,
+    /// Nickname to use, defaults to the one saved in the settings
+    #[arg(long)]
+    name: Option<String>,
+}
+
+/// How and where downloaded files are saved, shared by `receive` and `get`.
+#[derive(Args)]
+pub struct SaveArgs {
+    /// Where to save the files, defaults to the downloads folder
+    #[arg(long)]
+    out: Option<PathBuf>,
+    /// What to do with files that already exist: rename, skip or overwrite
+    #[arg(long)]
+    on_conflict: Option<ConflictPolicy>,
+    /// Refuse transfers bigger than this, e.g. 500MB or 20GB, or "unlimited"
+    #[arg(long)]
+    max_size: Option<SizeLimit>,
+}
+
+/// A download size limit as given on the command line.
+#[derive(Clone, Copy)]
+struct SizeLimit(Option<u64>);
+
+impl FromStr for SizeLimit {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("unlimited") {
+            return Ok(Self(None));
+        }
+        parse_size(s).map(|size| Self(Some(size)))
+    }
+}
+
+/// Process exit codes, so scripts can tell what went wrong. 2 is taken by clap for bad usage.
+#[derive(Debug, Clone, Copy)]
+pub enum Exit {
+    Success = 0,
+    /// The transfer itself failed, see stderr.
+    Failed = 1,
+    /// The signaling server couldn't be reached.
+    Unreachable = 3,
+    /// The server refused our nickname.
+    NicknameRejected = 4,
+    /// The recipient isn't in the room, or left before it got everything.
+    PeerNotFound = 5,
+    /// The recipient declined the offer.
+    Declined = 6,
+    /// Interrupted with Ctrl-C before the transfer finished.
+    Interrupted = 130,
+}
+
+impl From<Exit> for ExitCode {
+    fn from(exit: Exit) -> Self {
+        ExitCode::from(exit as u8)
+    }
+}
+
+/// `secret_key` is the device's, used for room commands. Shares and gets go straight to a
+/// ticket, so they make do with a throwaway one. `server` overrides the saved server url.
+pub async fn run(
+    command: Command,
+    mut settings: Settings,
+    server: Option<String>,
+    secret_key: SecretKey,
+) -> ExitCode {
+    let save = match &command {
+        Command::Room(RoomCommand::Receive { save, .. }) | Command::Get { save, .. } => Some(save),
+        _ => None,
+    };
+    let download_dir = save
+        .and_then(|save| save.out.clone())
+        .unwrap_or_else(|| dirs::download_dir().unwrap_or_else(|| PathBuf::from(".")));
+    if let Some(policy) = save.and_then(|save| save.on_conflict) {
+        settings.conflict_policy = policy;
+    }
+    if let Some(SizeLimit(limit)) = save.and_then(|save| save.max_size) {
+        settings.max_download_size = limit;
+    }
+    let nickname = match &command {
+        Command::Room(command) => command.session().name.clone(),
+        _ => None,
+    }
+    .unwrap_or_else(|| settings.nickname.clone());
+
+    if let Command::Room(RoomCommand::Send { paths, .. }) | Command::Share { paths, .. } = &command
+        && let Some(missing) = paths.iter().find(|p| !p.exists())
+    {
+        eprintln!("{} does not exist", missing.display());
+        return Exit::Failed.into();
+    }
+    // the store only exports to absolute paths
+    let download_dir = match std::fs::create_dir_all(&download_dir)
+        .and_then(|_| std::path::absolute(&download_dir))
+    {
+        Ok(download_dir) => download_dir,
+        Err(e) => {
+            eprintln!("Can't create {}: {e}", download_dir.display());
+            return Exit::Failed.into();
+        }
+    };
+
+    // unlike the GUI's, this store only lives as long as the command. It's ours alone, so
+    // nothing the user keeps gets removed with it
+    let store_dir = config::data_dir().join(format!("cli-{}", std::process::id()));
+    let exit = match command {
+        Command::Room(command) => {
+            room(
+                command,
+                settings,
+                server,
+                &nickname,
+                secret_key,
+                &store_dir,
+                &download_dir,
+            )
+            .await
+        }
+        Command::Share {
+            paths,
+            once,
+            expires,
+        } => share(paths, once, expires, &store_dir).await,
+        Command::Get { ticket, .. } => get(&ticket, &store_dir, &download_dir, &settings).await,
+    };
+    std::fs::remove_dir_all(&store_dir).ok();
+
+    exit.into()
+}
+
+async fn room(
+    command: RoomCommand,
+    settings: Settings,
+    server: Option<String>,
+    nickname: &str,
+    secret_key: SecretKey,
+    store_dir: &Path,
+    download_dir: &Path,
+) -> Exit {
+    let room = command.session().room.clone();
+    let max_size = settings.max_download_size;
+    let mut network = NativeNetwork::new(
+        store_dir.to_path_buf(),
+        download_dir.to_path_buf(),
+        settings,
+        server,
+        secret_key,
+    );
+
+    let exit = match join(&mut network, &room, nickname).await {
+        Ok(users) => match command {
+            RoomCommand::Send { paths, to, .. } => {
+                send(&mut network, users, paths, to, &room).await
+            }
+            RoomCommand::Receive { auto_accept, .. } => {
+                receive(&mut network, auto_accept, store_dir, download_dir, max_size).await
+            }
+            RoomCommand::Peers { .. } => {
+                let mut users = users;
+                users.sort();
+                for user in users {
+                    println!("{user}");
+                }
+                Exit::Success
+            }
+        },
+        Err(exit) => exit,
+    };
+
+    network.shutdown().await;
+    exit
+}
+
+/// Provides `paths` to anyone holding the printed ticket until Ctrl-C or until it expires after
+/// `expires` seconds. With `once`, only the first machine to use the ticket gets to download.
+async fn share(paths: Vec<PathBuf>, once: bool, expires: Option<u64>, store_dir: &Path) -> Exit {
+    let files = match collect_files(paths) {
+        Ok(files) => files,
+        Err(e) => {
+            eprintln!("Error: {e:#}");
+            return Exit::Failed;
+        }
+    };
+
+    let (tx, rx) = mpsc::unbounded_channel();
+    let secret_key = SecretKey::generate(&mut rand::rng());
+    let iroh_node = match IrohNode::new(store_dir.to_path_buf(), secret_key, tx.clone()).await {
+        Ok(iroh_node) => iroh_node,
+        Err(e) => {
+            eprintln!("Error: {e:#}");
+            return Exit::Failed;
+        }
+    };
+    tokio::spawn(report(rx));
+
+    eprintln!("Preparing {} file(s)...", files.len());
+    let id = next_transfer_id();
+    let tt = match iroh_node.import(id, files, tx).await {
+        Ok(tt) => tt,
+        Err(e) => {
+            eprintln!("Error: {e:#}");
+            return Exit::Failed;
+        }
+    };
+
+    eprintln!();
+    iroh_node.allow(id, if once { Audience::First } else { Audience::Anyone });
+    if let Some(expires) = expires
+        && let Err(e) = iroh_node.expire(id, Duration::from_secs(expires))
+    {
+        eprintln!("Error: {e:#}");
+        return Exit::Failed;
+    }
+
+    // make sure the ticket carries a relay or direct address to reach us at
+    iroh_node.endpoint.online().await;
+    let ticket = BlobTicket::new(iroh_node.endpoint.addr(), tt.hash(), BlobFormat::HashSeq);
+    let ticket = ticket.to_string();
+
+    if let Ok(code) = QrCode::new(&ticket) {
+        let qr = code
+            .render::<Dense1x2>()
+            .dark_color(Dense1x2::Light)
+            .light_color(Dense1x2::Dark)
+            .build();
+        eprintln!("{qr}");
+    }
+    println!("{ticket}");
+    eprintln!("Run `fling get <ticket>` on the other machine. Press Ctrl-C to stop sharing.");
+
+    let expired = async {
+        match expires {
+            Some(expires) => tokio::time::sleep(Duration::from_secs(expires)).await,
+            None => std::future::pending().await,
+        }
+    };
+    tokio::select! {
+        _ = tokio::signal::ctrl_c() => {}
+        _ = expired => {
+            eprintln!();
+            eprintln!("Ticket expired");
+        }
+    }
+    Exit::Success
+}
+
+/// Downloads the collection behind `ticket` straight from the machine sharing it.
+async fn get(ticket: &str, store_dir: &Path, download_dir: &Path, settings: &Settings) -> Exit {
+    let ticket = match BlobTicket::from_str(ticket) {
+        Ok(ticket) => ticket,
+        Err(e) => {
+            eprintln!("Invalid ticket: {e}");
+            return Exit::Failed;
+        }
+    };
+    let (tx, rx) = mpsc::unbounded_channel();
+    let secret_key = SecretKey::generate(&mut rand::rng());
+    let iroh_node = match IrohNode::new(store_dir.to_path_buf(), secret_key, tx.clone()).await {
+        Ok(iroh_node) => iroh_node,
+        Err(e) => {
+            eprintln!("Error: {e:#}");
+            return Exit::Failed;
+        }
+    };
+    tokio::spawn(report(rx));
+
+    let result = tokio::select! {
+        result = iroh_node.download(next_transfer_id(), &ticket, download_dir, settings, tx) => result,
+        _ = tokio::signal::ctrl_c() => return Exit::Interrupted,
+    };
+    match result {
+        Ok(files) => {
+            eprintln!();
+            for file in files {
+                println!("{}", file.display());
+            }
+            Exit::Success
+        }
+        Err(e) => {
+            eprintln!();
+            eprintln!("Error: {e:#}");
+            Exit::Failed
+        }
+    }
+}
+
+/// Prints what the node reports while working without the rest of the app around it.
+async fn report(mut rx: mpsc::UnboundedReceiver<AppEvent>) {
+    let mut progress = ProgressLine::default();
+    // one line per peer fetching what we share
+    let mut uploads = HashMap::<String, ProgressLine>::new();
+    while let Some(event) = rx.recv().await {
+        match event {
+            AppEvent::TransferUpdate(_, TransferState::Downloading) => eprintln!("Downloading..."),
+            AppEvent::TransferProgress(_, done, total) => progress.update(done, total),
+            AppEvent::TransferUpdate(_, TransferState::Exporting) => {
+                if let Some(summary) = progress.summary() {
+                    eprintln!();
+                    eprintln!("Fetched {summary}");
+                }
+            }
+            AppEvent::UploadProgress(_, peer, done, total) => {
+                let upload = uploads.entry(peer.clone()).or_insert_with(|| {
+                    eprintln!();
+                    eprintln!("{peer} is downloading...");
+                    ProgressLine::default()
+                });
+                upload.update(done, total);
+                if done >= total {
+                    eprintln!();
+                    match upload.summary() {
+                        Some(summary) => eprintln!("{peer} fetched {summary}"),
+                        None => eprintln!("{peer} fetched everything"),
+                    }
+                    uploads.remove(&peer);
+                }
+            }
+            AppEvent::EntriesRejected(entries) => print_rejected(&entries),
+            AppEvent::FatalError(e) => eprintln!("Error: {e:#}"),
+            _ => {}
+        }
+    }
+}
+
+/// Waits for the node to come up, joins `room` and returns who's already there.
+async fn join(network: &mut NativeNetwork, room: &str, nickname: &str) -> Result<Vec<String>, Exit> {
+    while let Some(event) = network.recv().await {
+        match event {
+            AppEvent::ReadyToPublishUser => {
+                network
+                    .send_ws(UIEvent::JoinRoom {
+                        room: room.to_string(),
+                        nickname: nickname.to_string(),
+                    })
+                    .ok();
+            }
+            AppEvent::RegisterSuccess(users) => {
+                let endpoint_id = network.endpoint_id();
+                let fingerprint = identity::fingerprint(&endpoint_id);
+                eprintln!("Joined room \"{room}\" as {nickname} ({fingerprint})");
+                return Ok(users);
+            }
+            AppEvent::RegisterRejected(reason) => {
+                eprintln!("Server rejected nickname: {reason}");
+                return Err(Exit::NicknameRejected);
+            }
+            AppEvent::Reconnecting(attempt) => check_reconnect(attempt)?,
+            AppEvent::ServerShuttingDown(retry_after) => server_restarting(retry_after),
+            AppEvent::FatalError(e) => {
+                eprintln!("Error: {e:#}");
+                return Err(Exit::Failed);
+            }
+            _ => {}
+        }
+    }
+    Err(Exit::Failed)
+}
+
+async fn send(
+    network: &mut NativeNetwork,
+    users: Vec<String>,
+    paths: Vec<PathBuf>,
+    to: String,
+    room: &str,
+) -> Exit {
+    if !users.contains(&to) {
+        eprintln!("{to} is not in room \"{room}\"");
+        return Exit::PeerNotFound;
+    }
+
+    let id = next_transfer_id();
+    network
+        .send_ws(UIEvent::PrepareFile {
+            id,
+            recipient: to.clone(),
+            files: paths.into_iter().map(FileHandle::from).collect(),
+        })
+        .ok();
+
+    // what our offer was signed with, answers to anything else aren't ours
+    let mut nonce = None;
+    let mut accepted = false;
+    let mut progress = ProgressLine::default();
+    let mut upload = ProgressLine::default();
+    loop {
+        let event = tokio::select! {
+            event = network.recv() => event,
+            _ = tokio::signal::ctrl_c() => return Exit::Interrupted,
+        };
+        let Some(event) = event else {
+            return Exit::Failed;
+        };
+
+        match event {
+            AppEvent::TransferUpdate(_, TransferState::Importing) => {
+                eprintln!("Preparing file(s)...");
+            }
+            AppEvent::TransferProgress(_, done, total) => progress.update(done, total),
+            AppEvent::TransferUpdate(_, TransferState::Offering) => {
+                eprintln!();
+                eprintln!("Waiting for {to} to accept...");
+            }
+            AppEvent::Offered(offered, signed) if offered == id => nonce = Some(signed),
+            AppEvent::OfferAccepted(answered, _, endpoint_id) if Some(answered) == nonce => {
+                accepted = true;
+                network
+                    .send_ws(UIEvent::GrantAccess { id, endpoint_id })
+                    .ok();
+                eprintln!("{to} accepted, sending...");
+            }
+            AppEvent::UploadProgress(_, _, done, total) => upload.update(done, total),
+            AppEvent::Delivered(answered, _) if Some(answered) == nonce => {
+                eprintln!();
+                match upload.summary() {
+                    Some(summary) => eprintln!("Delivered to {to}, {summary}"),
+                    None => eprintln!("Delivered to {to}"),
+                }
+                return Exit::Success;
+            }
+            AppEvent::OfferDeclined(answered, _) if Some(answered) == nonce => {
+                eprintln!("{to} declined");
+                return Exit::Declined;
+            }
+            AppEvent::RemoveUser(nickname) if nickname == to => {
+                eprintln!();
+                if accepted {
+                    eprintln!("{to} left before getting everything");
+                } else {
+                    eprintln!("{to} left the room");
+                }
+                return Exit::PeerNotFound;
+            }
+            AppEvent::Reconnecting(attempt) => {
+                if let Err(exit) = check_reconnect(attempt) {
+                    return exit;
+                }
+            }
+            AppEvent::ServerShuttingDown(retry_after) => server_restarting(retry_after),
+            AppEvent::TransferUpdate(_, TransferState::Failed(e)) => {
+                eprintln!();
+                eprintln!("Error: {e}");
+                return Exit::Failed;
+            }
+            AppEvent::FatalError(e) => {
+                eprintln!("Error: {e:#}");
+                return Exit::Failed;
+            }
+            _ => {}
+        }
+    }
+}
+
+async fn receive(
+    network: &mut NativeNetwork,
+    auto_accept: bool,
+    store_dir: &Path,
+    download_dir: &Path,
+    max_size: Option<u64>,
+) -> Exit {
+    eprintln!("Waiting for files, saving to {}", download_dir.display());
+
+    let mut downloading = false;
+    let mut progress = ProgressLine::default();
+    loop {
+        let event = tokio::select! {
+            event = network.recv() => event,
+            _ = tokio::signal::ctrl_c() => return Exit::Interrupted,
+        };
+        let Some(event) = event else {
+            return Exit::Failed;
+        };
+
+        match event {
+            AppEvent::IncomingOffer(offer) => {
+                if downloading {
+                    // one transfer per run, scripts shouldn't have to guess what they got
+                    network
+                        .send_ws(UIEvent::RespondOffer {
+                            offer_id: offer.id,
+                            accepted: false,
+                        })
+                        .ok();
+                    continue;
+                }
+
+                eprintln!(
+                    "{} ({}) wants to send you {} file(s), {}:",
+                    offer.sender,
+                    identity::fingerprint(&offer.sender_id),
+                    offer.files.len(),
+                    format_size(offer.size)
+                );
+                for file in &offer.files {
+                    eprintln!("  {file}");
+                }
+                if let Err(e) = check_size(offer.size, max_size, store_dir, download_dir) {
+                    network
+                        .send_ws(UIEvent::RespondOffer {
+                            offer_id: offer.id,
+                            accepted: false,
+                        })
+                        .ok();
+                    eprintln!("Error: {e:#}");
+                    return Exit::Failed;
+                }
+                let accepted = auto_accept
+                    || match confirm("Accept? [y/N] ").await {
+                        Some(accepted) => accepted,
+                        None => {
+                            // don't leave the sender waiting on an answer that won't come
+                            network
+                                .send_ws(UIEvent::RespondOffer {
+                                    offer_id: offer.id,
+                                    accepted: false,
+                                })
+                                .ok();
+                            return Exit::Interrupted;
+                        }
+                    };
+
+                network
+                    .send_ws(UIEvent::RespondOffer {
+                        offer_id: offer.id,
+                        accepted,
+                    })
+                    .ok();
+                if accepted {
+                    downloading = true;
+                    network
+                        .send_ws(UIEvent::DownloadFile {
+                            id: next_transfer_id(),
+                            offer,
+                        })
+                        .ok();
+                }
+            }
+            AppEvent::TransferUpdate(_, TransferState::Downloading) => eprintln!("Downloading..."),
+            AppEvent::TransferProgress(_, done, total) => progress.update(done, total),
+            AppEvent::EntriesRejected(entries) => print_rejected(&entries),
+            AppEvent::TransferUpdate(_, TransferState::Done) => {
+                eprintln!();
+                match progress.summary() {
+                    Some(summary) => eprintln!("Done, {summary}"),
+                    None => eprintln!("Done"),
+                }
+                return Exit::Success;
+            }
+            AppEvent::Reconnecting(attempt) => {
+                if let Err(exit) = check_reconnect(attempt) {
+                    return exit;
+                }
+            }
+            AppEvent::ServerShuttingDown(retry_after) => server_restarting(retry_after),
+            AppEvent::TransferUpdate(_, TransferState::Failed(e)) => {
+                eprintln!();
+                eprintln!("Error: {e}");
+                return Exit::Failed;
+            }
+            AppEvent::FatalError(e) => {
+                eprintln!();
+                eprintln!("Error: {e:#}");
+                return Exit::Failed;
+            }
+            _ => {}
+        }
+    }
+}
+
+fn print_rejected(entries: &[String]) {
+    eprintln!();
+    eprintln!("Some files weren't saved:");
+    for entry in entries {
+        eprintln!("  {entry}");
+    }
+}
+
+fn server_restarting(retry_after: u64) {
+    eprintln!("Server is restarting, reconnecting in {retry_after}s");
+}
+
+/// Gives up once we've been failing to reach the server for a while.
+fn check_reconnect(attempt: u32) -> Result<(), Exit> {
+    if attempt == 0 {
+        eprintln!("Lost connection to the server, reconnecting...");
+    } else if attempt >= MAX_CONNECT_ATTEMPTS {
+        eprintln!("Can't reach the server, giving up after {attempt} attempts");
+        return Err(Exit::Unreachable);
+    }
+    Ok(())
+}
+
+/// Asks a yes/no question on stdin, anything but yes (including no terminal at all) is a no.
+/// Returns `None` if interrupted with Ctrl-C before an answer came.
+async fn confirm(question: &'static str) -> Option<bool> {
+    eprint!("{question}");
+    io::stderr().flush().ok();
+
+    let (answer_tx, answer) = oneshot::channel();
+    // a plain thread rather than a blocking task, which would keep the runtime from shutting
+    // down while it waits on stdin
+    std::thread::spawn(move || {
+        let mut answer = String::new();
+        io::stdin().read_line(&mut answer).ok();
+        answer_tx
+            .send(matches!(answer.trim().to_lowercase().as_str(), "y" | "yes"))
+            .ok();
+    });
+
+    tokio::select! {
+        answer = answer => Some(answer.unwrap_or(false)),
+        _ = tokio::signal::ctrl_c() => {
+            eprintln!();
+            None
+        }
+    }
+}
+
+/// The progress bar redrawn on stderr while a transfer runs.
+#[derive(Default)]
+struct ProgressLine {
+    started: Option<Instant>,
+    throughput: Throughput,
+    total: u64,
+}
+
+impl ProgressLine {
+    fn update(&mut self, done: u64, total: u64) {
+        const WIDTH: usize = 30;
+
+        let started = *self.started.get_or_insert_with(Instant::now);
+        let now = started.elapsed().as_secs_f64();
+        self.throughput.update(done, now);
+        self.total = total;
+
+        let value = if total == 0 {
+            1.0
+        } else {
+            (done as f32 / total as f32).clamp(0.0, 1.0)
+        };
+        let filled = (value * WIDTH as f32) as usize;
+        // nothing to say about speed until the first sample is in
+        let mut speed = String::new();
+        if self.throughput.current() > 0.0 {
+            speed = format!(", {}", format_speed(self.throughput.current()));
+        }
+        if let Some(eta) = self.throughput.eta(total) {
+            speed.push_str(&format!(", {} left", format_duration(eta)));
+        }
+        eprint!(
+            "\r[{}{}] {:>3.0}% {} / {}{speed}   ",
+            "#".repeat(filled),
+            " ".repeat(WIDTH - filled),
+            value * 100.0,
+            format_size(done),
+            format_size(total),
+        );
+        io::stderr().flush().ok();
+    }
+
+    /// How long it all took and how fast it went on average, once there's been any progress.
+    fn summary(&self) -> Option<String> {
+        let average = self.throughput.average();
+        (average > 0.0).then(|| {
+            format!(
+                "{} in {} ({})",
+                format_size(self.total),
+                format_duration(self.throughput.elapsed()),
+                format_speed(average)
+            )
+        })
+    }
+}
@@ -0,0 +1,73 @@
+use std::{
+    fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+use anyhow::{anyhow, Context, Result};
+use iroh::SecretKey;
+use shared::{identity, settings::Settings};
+
+/// `<config dir>/fling`, where settings are kept between launches.
+pub fn config_dir() -> PathBuf {
+    dirs::config_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("fling")
+}
+
+/// `<local data dir>/fling`, for the blob store which can grow large.
+pub fn data_dir() -> PathBuf {
+    dirs::data_local_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("fling")
+}
+
+fn settings_path() -> PathBuf {
+    config_dir().join("settings.json")
+}
+
+fn secret_key_path() -> PathBuf {
+    config_dir().join("secret_key")
+}
+
+/// Loads the saved settings, falling back to the defaults if there are none (or they're broken).
+pub fn load_settings() -> Settings {
+    fs::read_to_string(settings_path())
+        .ok()
+        .and_then(|s| serde_json::from_str(&s).ok())
+        .unwrap_or_default()
+}
+
+pub fn save_settings(settings: &Settings) -> Result<()> {
+    fs::create_dir_all(config_dir()).context("Failed to create config dir")?;
+    let json = serde_json::to_string_pretty(settings)?;
+    fs::write(settings_path(), json).context("Failed to write settings")
+}
+
+/// Loads this device's iroh key, making one on first launch. Keeping it is what keeps our
+/// endpoint id, and so our fingerprint, the same between launches.
+pub fn load_secret_key() -> Result<SecretKey> {
+    let path = secret_key_path();
+    match fs::read_to_string(&path) {
+        Ok(hex) => identity::decode_secret_key(&hex)
+            .map(|bytes| SecretKey::from_bytes(&bytes))
+            .ok_or_else(|| anyhow!("{} is not a valid key", path.display())),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            let secret_key = SecretKey::generate(&mut rand::rng());
+            fs::create_dir_all(config_dir()).context("Failed to create config dir")?;
+            write_private(&path, &identity::encode_secret_key(&secret_key.to_bytes()))
+                .context("Failed to write secret key")?;
+            Ok(secret_key)
+        }
+        Err(e) => Err(anyhow!(e).context("Failed to read secret key")),
+    }
+}
+
+/// Writes a file only we can read, where the platform lets us.
+fn write_private(path: &Path, contents: &str) -> io::Result<()> {
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    options.open(path)?.write_all(contents.as_bytes())
+}
@@ -0,0 +1,341 @@
+use std::{collections::HashMap, time::Duration};
+
+use anyhow::{anyhow, bail, Context, Result};
+use futures_util::{SinkExt, StreamExt};
+use iroh::SecretKey;
+use shared::{
+    app_events::AppEvent,
+    identity,
+    websocket_messages::{room_name, Offer, WebSocketMessage, OFFER_TTL},
+};
+use tokio::{
+    net::TcpStream,
+    sync::{
+        mpsc::{UnboundedReceiver, UnboundedSender},
+        watch,
+    },
+};
+use tokio_tungstenite::{connect_async, tungstenite::Message, MaybeTlsStream, WebSocketStream};
+use tracing::{info, warn};
+
+type WsStream = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+const MAX_BACKOFF: Duration = Duration::from_secs(30);
+/// How long the server gets to send its challenge once we're connected.
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Owns the websocket to the signaling server and transparently reconnects it.
+///
+/// Everything the app wants to send goes through `outgoing`, so callers never have to care
+/// whether the socket is currently up.
+pub struct Connection {
+    /// server url, switching it drops the current socket and connects to the new one
+    url: watch::Receiver<String>,
+    /// our iroh endpoint's key, proves to the server which endpoint we are
+    secret_key: SecretKey,
+    tx: UnboundedSender<AppEvent>,
+    outgoing: UnboundedReceiver<WebSocketMessage>,
+    /// last room we asked to join, replayed if the server no longer knows our session
+    join: Option<WebSocketMessage>,
+    resume_token: Option<String>,
+    /// message that was being written when the socket dropped
+    unsent: Option<WebSocketMessage>,
+    /// how long the server asked us to wait before reconnecting, when it shuts down
+    retry_after: Option<Duration>,
+    /// sender endpoint and nonce of every offer we got -> when it was signed, a signed offer
+    /// only counts once. Forgotten once it's too old to be accepted anyway
+    seen_offers: HashMap<(String, u64), u64>,
+}
+
+impl Connection {
+    pub fn new(
+        url: watch::Receiver<String>,
+        outgoing: UnboundedReceiver<WebSocketMessage>,
+        secret_key: SecretKey,
+        tx: UnboundedSender<AppEvent>,
+    ) -> Self {
+        Self {
+            url,
+            secret_key,
+            tx,
+            outgoing,
+            join: None,
+            resume_token: None,
+            unsent: None,
+            retry_after: None,
+            seen_offers: HashMap::new(),
+        }
+    }
+
+    /// Connects to the server and proves who we are, retrying with exponential backoff until it
+    /// works. A new url is tried right away.
+    pub async fn connect(&mut self) -> WsStream {
+        let mut attempt = 0;
+        loop {
+            let url = self.url.borrow().clone();
+            match self.try_connect(&url).await {
+                Ok(ws_stream) => {
+                    info!(%url, "connected");
+                    return ws_stream;
+                }
+                Err(e) => {
+                    attempt += 1;
+                    self.tx.send(AppEvent::Reconnecting(attempt)).ok();
+
+                    let delay = Duration::from_millis(500 * 2u64.pow(attempt.min(6))).min(MAX_BACKOFF);
+                    warn!("connecting to {url} failed ({e:#}), retrying in {delay:?}");
+                    tokio::select! {
+                        _ = tokio::time::sleep(delay) => {}
+                        Ok(()) = self.url.changed() => {
+                            // a session on the old server means nothing to the new one
+                            self.resume_token = None;
+                            attempt = 0;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    async fn try_connect(&self, url: &str) -> Result<WsStream> {
+        let (mut ws_stream, _) = connect_async(url).await?;
+        tokio::time::timeout(HANDSHAKE_TIMEOUT, self.authenticate(&mut ws_stream))
+            .await
+            .context("Server never sent a challenge")??;
+        Ok(ws_stream)
+    }
+
+    /// Signs the challenge the server opens every connection with.
+    async fn authenticate(&self, ws_stream: &mut WsStream) -> Result<()> {
+        while let Some(frame) = ws_stream.next().await {
+            let Message::Text(bytes) = frame? else {
+                continue;
+            };
+            let Ok(WebSocketMessage::Challenge { nonce }) = serde_json::from_str(bytes.as_str())
+            else {
+                continue;
+            };
+
+            let secret_key = self.secret_key.to_bytes();
+            let msg = WebSocketMessage::Authenticate {
+                endpoint_id: identity::endpoint_id(&secret_key),
+                signature: identity::sign(&secret_key, &identity::challenge_payload(&nonce)),
+            };
+            ws_stream.send(Message::Text(msg.to_json().into())).await?;
+            return Ok(());
+        }
+        bail!("Server closed the connection before the challenge")
+    }
+
+    /// Pumps messages both ways, reconnecting whenever the socket drops. Returns once the app
+    /// drops its end of `outgoing`, after telling the server we're gone.
+    pub async fn run(mut self, mut ws_stream: WsStream) {
+        while self.drive(ws_stream).await {
+            self.tx.send(AppEvent::Reconnecting(0)).ok();
+            if let Some(delay) = self.retry_after.take() {
+                tokio::time::sleep(delay).await;
+            }
+            ws_stream = self.connect().await;
+
+            // take our old slot back, or start over if we never had one
+            let hello = match (&self.resume_token, &self.join) {
+                (Some(token), _) => Some(WebSocketMessage::Resume {
+                    token: token.clone(),
+                }),
+                (None, Some(join)) => Some(join.clone()),
+                (None, None) => None,
+            };
+            match hello {
+                Some(msg) => {
+                    ws_stream
+                        .send(Message::Text(msg.to_json().into()))
+                        .await
+                        .ok();
+                }
+                None => {
+                    self.tx.send(AppEvent::ReadyToPublishUser).ok();
+                }
+            }
+        }
+    }
+
+    /// Returns `true` if the socket dropped and `false` if the app is shutting down.
+    async fn drive(&mut self, ws_stream: WsStream) -> bool {
+        let (mut sender, mut receiver) = ws_stream.split();
+
+        if let Some(msg) = self.unsent.take()
+            && sender
+                .send(Message::Text(msg.to_json().into()))
+                .await
+                .is_err()
+        {
+            self.unsent = Some(msg);
+            return true;
+        }
+
+        let mut shutting_down = false;
+        loop {
+            // the server's keepalive pings are answered by tungstenite while we keep polling
+            // the stream, so this loop must never block on anything else
+            tokio::select! {
+                frame = receiver.next() => match frame {
+                    Some(Ok(msg)) => {
+                        if let Some(reply) = self.process_message(msg) {
+                            sender.send(Message::Text(reply.to_json().into())).await.ok();
+                        }
+                        if self.retry_after.is_some() {
+                            // leave now instead of waiting for the server to cut us off
+                            sender.send(Message::Close(None)).await.ok();
+                            return true;
+                        }
+                    }
+                    Some(Err(e)) => {
+                        warn!("websocket error: {e}");
+                        return true;
+                    }
+                    None => return true,
+                },
+                changed = self.url.changed(), if !shutting_down => {
+                    if changed.is_err() {
+                        // the outgoing channel is closing as well, let it flush what's left and
+                        // send the close frame
+                        shutting_down = true;
+                        continue;
+                    }
+                    // a session on the old server means nothing to the new one
+                    self.resume_token = None;
+                    return true;
+                }
+                msg = self.outgoing.recv() => {
+                    let Some(msg) = msg else {
+                        // a clean close frees our nickname right away instead of after the resume grace
+                        sender.send(Message::Close(None)).await.ok();
+                        return false;
+                    };
+                    if let WebSocketMessage::JoinRoom { .. } = &msg {
+                        self.join = Some(msg.clone());
+                    }
+                    if sender.send(Message::Text(msg.to_json().into())).await.is_err() {
+                        self.unsent = Some(msg);
+                        return true;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Forwards a server message to the UI, returning anything we need to answer right away.
+    fn process_message(&mut self, msg: Message) -> Option<WebSocketMessage> {
+        let Message::Text(bytes) = msg else {
+            return None;
+        };
+        let tx = &self.tx;
+
+        match serde_json::from_str::<WebSocketMessage>(bytes.as_str()) {
+            Ok(websocket_msg) => match websocket_msg {
+                WebSocketMessage::RegisterSuccess {
+                    users,
+                    resume_token,
+                } => {
+                    self.resume_token = Some(resume_token);
+                    tx.send(AppEvent::RegisterSuccess(users)).ok();
+                }
+                WebSocketMessage::ResumeFailed => {
+                    // our slot expired, join again like it's the first time
+                    self.resume_token = None;
+                    return self.join.clone();
+                }
+                WebSocketMessage::RegisterRejected { reason } => {
+                    tx.send(AppEvent::RegisterRejected(reason)).ok();
+                }
+                WebSocketMessage::UserJoined(nickname) => {
+                    tx.send(AppEvent::AddNewUser(nickname)).ok();
+                }
+                WebSocketMessage::UserLeft(nickname) => {
+                    tx.send(AppEvent::RemoveUser(nickname)).ok();
+                }
+                WebSocketMessage::IncomingOffer(offer) => {
+                    if let Err(e) = self.check_offer(&offer) {
+                        warn!(offer = offer.id, sender = %offer.sender, "dropping offer: {e}");
+                        // lets the server forget about it
+                        return Some(WebSocketMessage::RespondOffer {
+                            offer_id: offer.id,
+                            accepted: false,
+                        });
+                    }
+                    self.tx.send(AppEvent::IncomingOffer(offer)).ok();
+                }
+                WebSocketMessage::OfferAccepted {
+                    nonce,
+                    recipient,
+                    endpoint_id,
+                    ..
+                } => {
+                    tx.send(AppEvent::OfferAccepted(nonce, recipient, endpoint_id))
+                        .ok();
+                }
+                WebSocketMessage::OfferDeclined {
+                    nonce, recipient, ..
+                } => {
+                    tx.send(AppEvent::OfferDeclined(nonce, recipient)).ok();
+                }
+                WebSocketMessage::Delivered {
+                    nonce, recipient, ..
+                } => {
+                    tx.send(AppEvent::Delivered(nonce, recipient)).ok();
+                }
+                WebSocketMessage::ServerShuttingDown { retry_after } => {
+                    info!(retry_after, "server is shutting down");
+                    self.retry_after = Some(Duration::from_secs(retry_after));
+                    tx.send(AppEvent::ServerShuttingDown(retry_after)).ok();
+                }
+                WebSocketMessage::ErrorDeserializingJson(e) => {
+                    tx.send(AppEvent::FatalError(
+                        anyhow!(e).context("Server JSON error"),
+                    ))
+                    .ok();
+                }
+                _ => {}
+            },
+            Err(e) => {
+                tx.send(AppEvent::FatalError(
+                    anyhow!(e).context("Message parse failed"),
+                ))
+                .ok();
+            }
+        }
+
+        None
+    }
+
+    /// Makes sure a signed offer is meant for us, recent and isn't one we've already had, so one
+    /// can't be relayed to someone else or sent to us again as a new one.
+    fn check_offer(&mut self, offer: &Offer) -> Result<()> {
+        offer
+            .verify()
+            .map_err(|e| anyhow!("it isn't signed by its sender: {e}"))?;
+        let Some(WebSocketMessage::JoinRoom { room, nickname }) = &self.join else {
+            bail!("we haven't joined a room");
+        };
+        if offer.recipient != nickname.trim() || offer.room != room_name(room) {
+            bail!("it was signed for {} in {}", offer.recipient, offer.room);
+        }
+
+        let ttl = OFFER_TTL.as_millis() as u64;
+        let now = crate::unix_millis();
+        // a sender whose clock is ahead gets as much leeway, but no more so these stay bounded
+        if offer.signed_at.abs_diff(now) > ttl {
+            bail!("it was signed too long ago");
+        }
+        self.seen_offers
+            .retain(|_, signed_at| signed_at.abs_diff(now) <= ttl);
+        if self
+            .seen_offers
+            .insert((offer.sender_id.clone(), offer.nonce), offer.signed_at)
+            .is_some()
+        {
+            bail!("it was sent before");
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,60 @@
+use std::{
+    fs,
+    io::Write,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{Context, Result};
+use shared::{app_events::AppEvent, history::HistoryEntry};
+use tokio::sync::mpsc::UnboundedSender;
+
+/// Finished transfers, one JSON object per line so recording one only appends to the file.
+pub struct History {
+    path: PathBuf,
+    tx: UnboundedSender<AppEvent>,
+}
+
+impl History {
+    pub fn new(config_dir: &Path, tx: UnboundedSender<AppEvent>) -> Self {
+        Self {
+            path: config_dir.join("history.jsonl"),
+            tx,
+        }
+    }
+
+    /// Shows everything recorded so far to the UI, skipping lines that don't parse.
+    pub fn announce(&self) {
+        let entries = fs::read_to_string(&self.path)
+            .map(|s| {
+                s.lines()
+                    .filter_map(|line| serde_json::from_str(line).ok())
+                    .collect()
+            })
+            .unwrap_or_default();
+        self.tx.send(AppEvent::History(entries)).ok();
+    }
+
+    pub fn record(&self, mut entry: HistoryEntry) {
+        entry.finished_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default();
+        if let Err(e) = self.append(&entry) {
+            self.tx.send(AppEvent::FatalError(e)).ok();
+        }
+        self.tx.send(AppEvent::History(vec![entry])).ok();
+    }
+
+    fn append(&self, entry: &HistoryEntry) -> Result<()> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir).context("Failed to create config dir")?;
+        }
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .context("Failed to open history")?;
+        writeln!(file, "{}", serde_json::to_string(entry)?).context("Failed to write history")
+    }
+}
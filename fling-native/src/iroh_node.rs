@@ -1,69 +1,232 @@
-use std::path::PathBuf;
+use std::{
+    collections::{HashMap, HashSet},
+    path::{Component, Path, PathBuf},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
 
-use anyhow::{anyhow, Result};
+use anyhow::{anyhow, bail, Context, Result};
 use futures_util::{stream, StreamExt};
-use iroh::{protocol::Router, Endpoint};
+use iroh::{protocol::Router, Endpoint, EndpointId, SecretKey};
 use iroh_blobs::{
     api::{
         blobs::{AddBytesOptions, AddPathOptions, AddProgressItem},
+        remote::GetProgressItem,
         TempTag,
     },
     format::collection::Collection,
+    get::request::get_hash_seq_and_sizes,
+    provider::events::{
+        AbortReason, ConnectMode, EventMask, EventSender, ProviderMessage, RequestMode,
+        RequestUpdate,
+    },
     store::{
         fs::{self, FsStore},
         mem::MemStore,
     },
-    BlobsProtocol,
+    ticket::BlobTicket,
+    BlobsProtocol, Hash,
 };
 use n0_future::BufferedStreamExt;
-use shared::app_events::AppEvent;
+use shared::{
+    app_events::AppEvent,
+    settings::{ConflictPolicy, Settings},
+    transfer::{TransferId, TransferState},
+    units::format_size,
+    upload::{self, Upload},
+};
 use tokio::sync::mpsc::UnboundedSender;
+use tracing::{info, info_span, warn, Instrument};
+use walkdir::WalkDir;
+
+/// Largest collection listing we'll fetch, 32 bytes per file so about a million files. It only
+/// guards against a bogus listing, the size of the files themselves is checked separately.
+const MAX_HASH_SEQ_SIZE: u64 = 32 * 1024 * 1024;
+
+/// Size of the chunk groups blobs are sent in, see [`SentBytes`].
+const CHUNK_GROUP_SIZE: u64 = 16 * 1024;
+
+/// Who may fetch a collection we imported.
+pub type Audience = upload::Audience<EndpointId>;
+type Uploads = upload::Uploads<Hash, EndpointId, Instant>;
 
 pub struct IrohNode {
     pub endpoint: Endpoint,
     pub store: FsStore,
-    pub blobs: BlobsProtocol,
+    /// where the store keeps its blobs, downloads land here before they're exported
+    pub store_dir: PathBuf,
+    uploads: Uploads,
     _router: Router,
 }
 
 impl IrohNode {
-    pub async fn new(download_dir: PathBuf, path: String) -> Result<Self> {
-        let endpoint = Endpoint::bind().await?;
-        let store = FsStore::load(download_dir.join(format!("fling-{}", path))).await?;
-        let blobs = BlobsProtocol::new(&store, None);
+    /// Only collections made by [`Self::import`] are served, to whoever [`Self::allow`] lets
+    /// fetch them. Peers fetching one show up on `tx` as upload progress.
+    pub async fn new(
+        store_dir: PathBuf,
+        secret_key: SecretKey,
+        tx: UnboundedSender<AppEvent>,
+    ) -> Result<Self> {
+        let endpoint = Endpoint::builder().secret_key(secret_key).bind().await?;
+        let store = FsStore::load(&store_dir).await?;
+        let (events, provider_events) = EventSender::channel(
+            32,
+            EventMask {
+                connected: ConnectMode::Notify,
+                get: RequestMode::InterceptLog,
+                // collections only ever go out through plain get requests
+                get_many: RequestMode::Disabled,
+                push: RequestMode::Disabled,
+                ..EventMask::DEFAULT
+            },
+        );
+        let blobs = BlobsProtocol::new(&store, Some(events));
         let router = Router::builder(endpoint.clone())
-            .accept(iroh_blobs::ALPN, blobs.clone())
+            .accept(iroh_blobs::ALPN, blobs)
             .spawn();
 
+        let uploads = Uploads::default();
+        let uploads_clone = uploads.clone();
+        tokio::spawn(async move {
+            let mut provider_events = provider_events;
+            // connection id -> the peer on the other end
+            let mut peers = HashMap::new();
+            while let Some(event) = provider_events.recv().await {
+                match event {
+                    ProviderMessage::ClientConnectedNotify(msg) => {
+                        if let Some(endpoint_id) = msg.endpoint_id {
+                            peers.insert(msg.connection_id, endpoint_id);
+                        }
+                    }
+                    ProviderMessage::ConnectionClosed(msg) => {
+                        peers.remove(&msg.connection_id);
+                    }
+                    ProviderMessage::GetRequestReceived(msg) => {
+                        let peer = peers.get(&msg.connection_id).copied();
+                        let upload = uploads_clone
+                            .lock()
+                            .unwrap()
+                            .get_mut(&msg.request.hash)
+                            .and_then(|upload| {
+                                let admitted = upload.admit(peer, Instant::now());
+                                admitted.then_some((upload.id, upload.size))
+                            });
+                        let Some((id, total)) = upload else {
+                            warn!(
+                                hash = %msg.request.hash,
+                                ?peer,
+                                "refused a fetch nobody was granted"
+                            );
+                            msg.tx.send(Err(AbortReason::Permission)).await.ok();
+                            continue;
+                        };
+                        msg.tx.send(Ok(())).await.ok();
+
+                        let peer = peer
+                            .map(|peer| peer.fmt_short().to_string())
+                            .unwrap_or_else(|| "unknown peer".to_string());
+                        let tx = tx.clone();
+                        let mut updates = msg.rx;
+                        let span = info_span!("transfer", id);
+                        tokio::spawn(
+                            async move {
+                                info!(%peer, "upload started");
+                                let mut sent = SentBytes::default();
+                                while let Ok(Some(update)) = updates.recv().await {
+                                    let outcome = match update {
+                                        RequestUpdate::Completed(_) => Some("completed"),
+                                        RequestUpdate::Aborted(_) => Some("aborted"),
+                                        _ => None,
+                                    };
+                                    sent.update(update);
+                                    let done = sent.total().min(total);
+                                    tx.send(AppEvent::UploadProgress(
+                                        id,
+                                        peer.clone(),
+                                        done,
+                                        total,
+                                    ))
+                                    .ok();
+                                    if let Some(outcome) = outcome {
+                                        info!(%peer, sent = done, total, "upload {outcome}");
+                                        break;
+                                    }
+                                }
+                            }
+                            .instrument(span),
+                        );
+                    }
+                    _ => {}
+                }
+            }
+        });
+
         Ok(Self {
             endpoint,
             store,
-            blobs,
+            store_dir,
+            uploads,
             _router: router,
         })
     }
 
+    /// Lets `audience` fetch what transfer `id` imported.
+    pub fn allow(&self, id: TransferId, audience: Audience) {
+        let mut uploads = self.uploads.lock().unwrap();
+        for upload in uploads.values_mut().filter(|upload| upload.id == id) {
+            upload.audience = audience;
+        }
+    }
+
+    /// Stops serving what transfer `id` imported once `after` has passed. Fails if that's
+    /// further out than the clock can tell.
+    pub fn expire(&self, id: TransferId, after: Duration) -> Result<()> {
+        let expires = Instant::now()
+            .checked_add(after)
+            .context("Expiry is too far in the future")?;
+        let mut uploads = self.uploads.lock().unwrap();
+        for upload in uploads.values_mut().filter(|upload| upload.id == id) {
+            upload.expires = Some(expires);
+        }
+        Ok(())
+    }
+
+    /// Refuses any further request for what transfer `id` imported, returns whether there was
+    /// anything to revoke.
+    pub fn revoke(&self, id: TransferId) -> bool {
+        let mut uploads = self.uploads.lock().unwrap();
+        let before = uploads.len();
+        uploads.retain(|_, upload| upload.id != id);
+        uploads.len() != before
+    }
+
+    /// Imports `files`, as returned by [`collect_files`], into a single collection, reporting
+    /// the bytes hashed so far. Nobody may fetch the collection until [`Self::allow`]ed, and
+    /// fetches of it are reported as uploads of `id`.
+    ///
+    /// Dropping the returned future part way through drops the temp tags of whatever was
+    /// already imported, so the store is free to clean those blobs up.
     pub async fn import(
         &self,
-        files: Vec<PathBuf>,
+        id: TransferId,
+        files: Vec<(String, PathBuf)>,
         tx: UnboundedSender<AppEvent>,
     ) -> Result<TempTag> {
-        let collection = files
-            .into_iter()
-            .map(|p| {
-                let name = p
-                    .file_name()
-                    .and_then(|a| a.to_str())
-                    .unwrap_or_default()
-                    .to_string();
-                (name, p)
-            })
-            .collect::<Vec<_>>();
+        let total = files
+            .iter()
+            .filter_map(|(_, path)| std::fs::metadata(path).ok())
+            .map(|m| m.len())
+            .sum::<u64>();
+        let done = Arc::new(AtomicU64::new(0));
 
-        let infos = n0_future::stream::iter(collection)
+        let infos = n0_future::stream::iter(files)
             .map(|(name, path)| {
                 let store = self.store.clone();
-                let tx_clone = tx.clone();
+                let done = done.clone();
+                let tx = tx.clone();
                 async move {
                     let import = store.add_path_with_opts(AddPathOptions {
                         path,
@@ -71,28 +234,42 @@ impl IrohNode {
                         format: iroh_blobs::BlobFormat::Raw,
                     });
                     let mut stream = import.stream().await;
-                    let temp_tag = loop {
-                        if let Some(item) = stream.next().await {
-                            match item {
-                                AddProgressItem::Done(tt) => {
-                                    break tt;
-                                }
-                                AddProgressItem::Error(e) => {
-                                    let context = format!("Error importing {}", name);
-                                    tx_clone
-                                        .send(AppEvent::FatalError(anyhow!(e).context(context)))
-                                        .ok();
-                                }
-                                _ => {}
+                    // files are imported side by side, each adds what it got through since its
+                    // last update to the shared count
+                    let mut size = 0;
+                    let mut reported = 0;
+                    while let Some(item) = stream.next().await {
+                        match item {
+                            AddProgressItem::Size(s) => size = s,
+                            AddProgressItem::OutboardProgress(offset) => {
+                                let delta = offset.saturating_sub(reported);
+                                let progress = done.fetch_add(delta, Ordering::Relaxed) + delta;
+                                reported += delta;
+                                tx.send(AppEvent::TransferProgress(id, progress, total))
+                                    .ok();
+                            }
+                            AddProgressItem::Done(tt) => {
+                                // small files are done before reporting any progress
+                                let delta = size.saturating_sub(reported);
+                                let progress = done.fetch_add(delta, Ordering::Relaxed) + delta;
+                                tx.send(AppEvent::TransferProgress(id, progress, total))
+                                    .ok();
+                                return Ok((name, tt));
                             }
+                            AddProgressItem::Error(e) => {
+                                return Err(anyhow!(e).context(format!("Error importing {name}")));
+                            }
+                            _ => {}
                         }
-                    };
-                    (name, temp_tag)
+                    }
+                    bail!("Import of {name} stopped unexpectedly")
                 }
             })
             .buffered_unordered(8)
             .collect::<Vec<_>>()
-            .await;
+            .await
+            .into_iter()
+            .collect::<Result<Vec<_>>>()?;
 
         let (collection, tags) = infos
             .into_iter()
@@ -101,7 +278,443 @@ impl IrohNode {
 
         let tt = collection.store(&self.store).await?;
         drop(tags);
+        self.uploads
+            .lock()
+            .unwrap()
+            .insert(tt.hash(), Upload::new(id, total));
 
         Ok(tt)
     }
+
+    /// Fetches whatever part of the ticket's collection we don't have yet and exports every
+    /// file into `download_dir`, reporting progress along the way. Fails before fetching
+    /// anything if the collection is over the size limit or won't fit.
+    ///
+    /// Entries whose name could escape `download_dir`, or that already exist when the conflict
+    /// policy says to skip them, are left out and reported through `tx`.
+    pub async fn download(
+        &self,
+        id: TransferId,
+        ticket: &BlobTicket,
+        download_dir: &Path,
+        settings: &Settings,
+        tx: UnboundedSender<AppEvent>,
+    ) -> Result<Vec<PathBuf>> {
+        tx.send(AppEvent::TransferUpdate(id, TransferState::Downloading))
+            .ok();
+        let local_info = self
+            .store
+            .remote()
+            .local(ticket.hash_and_format())
+            .await
+            .map_err(|e| anyhow!(e).context("Failed to get local info"))?;
+
+        if !local_info.is_complete() {
+            let connection = self
+                .endpoint
+                .connect(ticket.addr().id, iroh_blobs::ALPN)
+                .await
+                .map_err(|e| anyhow!(e).context("Failed to connect to sender"))?;
+            let (_, size) = get_hash_seq_and_sizes(
+                &connection,
+                &ticket.hash(),
+                MAX_HASH_SEQ_SIZE,
+                None,
+            )
+            .await
+            .map_err(|e| anyhow!(e).context("Failed to get file(s) size"))?;
+
+            // skip the 1st index, bcs its the collection blob and obv we don't need it
+            let actual_size = size.iter().skip(1).sum::<u64>();
+            check_size(
+                actual_size,
+                settings.max_download_size,
+                &self.store_dir,
+                download_dir,
+            )?;
+            let get = self
+                .store
+                .remote()
+                .execute_get(connection, local_info.missing());
+            let mut stream = get.stream();
+            while let Some(item) = stream.next().await {
+                match item {
+                    GetProgressItem::Progress(b) => {
+                        tx.send(AppEvent::TransferProgress(id, b, actual_size)).ok();
+                    }
+                    GetProgressItem::Done(_) => break,
+                    GetProgressItem::Error(e) => {
+                        return Err(anyhow!(e).context("Error downloading one of the files"));
+                    }
+                }
+            }
+        }
+
+        tx.send(AppEvent::TransferUpdate(id, TransferState::Exporting))
+            .ok();
+        let collection = Collection::load(ticket.hash(), self.store.as_ref())
+            .await
+            .context("Error loading collection")?;
+
+        let mut exported = Vec::new();
+        let mut rejected = Vec::new();
+        for (name, hash) in collection.into_iter() {
+            let Some(relative) = sanitize_name(&name) else {
+                rejected.push(format!("{name}: unsafe file name"));
+                continue;
+            };
+
+            let mut path = download_dir.join(relative);
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)
+                    .with_context(|| format!("Error creating folder {}", parent.display()))?;
+            }
+            if path.exists() {
+                match settings.conflict_policy {
+                    ConflictPolicy::Rename => path = free_path(&path),
+                    ConflictPolicy::Skip => {
+                        rejected.push(format!("{name}: already exists"));
+                        continue;
+                    }
+                    ConflictPolicy::Overwrite => {}
+                }
+            }
+
+            // a half written file must never show up under its real name
+            let part = PartFile::new(&path);
+            self.store
+                .blobs()
+                .export(hash, part.path.clone())
+                .await
+                .map_err(|e| {
+                    anyhow!(e).context(format!("Error exporting file {}", path.display()))
+                })?;
+            part.persist(&path)?;
+            exported.push(path);
+        }
+
+        if !rejected.is_empty() {
+            for entry in &rejected {
+                warn!("didn't save {entry}");
+            }
+            tx.send(AppEvent::EntriesRejected(rejected)).ok();
+        }
+
+        Ok(exported)
+    }
+}
+
+/// Bytes of blob data a single get request has sent so far.
+///
+/// The provider only says how far into the current blob it got. A peer checking sizes fetches
+/// just the last chunk of each blob, so the first offset seen for a blob is taken to cover one
+/// chunk group rather than everything before it.
+#[derive(Default)]
+struct SentBytes {
+    /// bytes of the blobs before the current one
+    previous: u64,
+    /// bytes of the current blob
+    current: u64,
+    /// where in the current blob the last update ended
+    offset: Option<u64>,
+}
+
+impl SentBytes {
+    fn update(&mut self, update: RequestUpdate) {
+        match update {
+            RequestUpdate::Started(_) => {
+                self.previous += self.current;
+                self.current = 0;
+                self.offset = None;
+            }
+            RequestUpdate::Progress(progress) => {
+                let end = progress.end_offset;
+                let start = self.offset.unwrap_or_else(|| {
+                    end.saturating_sub(1) / CHUNK_GROUP_SIZE * CHUNK_GROUP_SIZE
+                });
+                self.current += end.saturating_sub(start);
+                self.offset = Some(end);
+            }
+            _ => {}
+        }
+    }
+
+    fn total(&self) -> u64 {
+        self.previous + self.current
+    }
+}
+
+/// Makes sure a transfer of `size` bytes is within `max_size` and fits on disk, so a download
+/// that can't finish is refused before it starts.
+///
+/// Files are fetched into the store under `store_dir` and then exported into `download_dir`, so
+/// there has to be room for both copies, twice the size when they share a filesystem.
+pub fn check_size(
+    size: u64,
+    max_size: Option<u64>,
+    store_dir: &Path,
+    download_dir: &Path,
+) -> Result<()> {
+    if let Some(max_size) = max_size
+        && size > max_size
+    {
+        bail!(
+            "Transfer is {}, over the {} download limit",
+            format_size(size),
+            format_size(max_size)
+        );
+    }
+    check_space(size, store_dir, download_dir)
+}
+
+/// Makes sure there's room on disk for a transfer of `size` bytes, see [`check_size`].
+pub fn check_space(size: u64, store_dir: &Path, download_dir: &Path) -> Result<()> {
+    // the store may not have been created yet
+    let store_dir = store_dir
+        .ancestors()
+        .find(|dir| dir.exists())
+        .unwrap_or(store_dir);
+    let needed = if same_filesystem(store_dir, download_dir) {
+        vec![(download_dir, size.saturating_mul(2))]
+    } else {
+        vec![(store_dir, size), (download_dir, size)]
+    };
+    for (dir, needed) in needed {
+        let available = fs4::available_space(dir)
+            .with_context(|| format!("Can't tell free space in {}", dir.display()))?;
+        if needed > available {
+            bail!(
+                "Transfer is {} and needs {} of room counting the copy kept until it's saved, \
+                 but only {} is free in {}",
+                format_size(size),
+                format_size(needed),
+                format_size(available),
+                dir.display()
+            );
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(unix)]
+fn same_filesystem(a: &Path, b: &Path) -> bool {
+    use std::os::unix::fs::MetadataExt;
+
+    match (std::fs::metadata(a), std::fs::metadata(b)) {
+        (Ok(a), Ok(b)) => a.dev() == b.dev(),
+        // can't tell, assume the worst
+        _ => true,
+    }
+}
+
+/// Same drive, which is as close as we get without platform specific calls.
+#[cfg(not(unix))]
+fn same_filesystem(a: &Path, b: &Path) -> bool {
+    a.components().next() == b.components().next()
+}
+
+/// Expands `paths` into the files to send, paired with their name in the collection.
+///
+/// Files are named after themselves and folders are walked recursively, each file inside named
+/// by its path relative to the folder's parent (e.g. `photos/2024/a.jpg`) so the receiver can
+/// rebuild the tree.
+pub fn collect_files(paths: Vec<PathBuf>) -> Result<Vec<(String, PathBuf)>> {
+    let mut files = Vec::new();
+    let mut names = HashSet::new();
+
+    for path in paths {
+        let path = path
+            .canonicalize()
+            .with_context(|| format!("Can't read {}", path.display()))?;
+        let base = path.parent().map(Path::to_path_buf).unwrap_or_default();
+
+        for entry in WalkDir::new(&path).follow_links(true).sort_by_file_name() {
+            let entry = entry.context("Error reading folder")?;
+            if !entry.file_type().is_file() {
+                continue;
+            }
+
+            let name = entry
+                .path()
+                .strip_prefix(&base)
+                .unwrap_or(entry.path())
+                .components()
+                .filter_map(|c| match c {
+                    Component::Normal(c) => Some(c.to_string_lossy()),
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+                .join("/");
+            if !names.insert(name.clone()) {
+                bail!("More than one file would be sent as \"{name}\"");
+            }
+            files.push((name, entry.into_path()));
+        }
+    }
+
+    Ok(files)
+}
+
+/// Turns a collection entry name into a path that's guaranteed to stay inside the download
+/// folder, or `None` if the sender tried anything funny.
+///
+/// Only plain `/` separated names are accepted: no absolute paths, no `.` or `..`, no empty
+/// components and nothing a Windows filesystem would read as a separator, drive or device.
+fn sanitize_name(name: &str) -> Option<PathBuf> {
+    let mut path = PathBuf::new();
+    for part in name.split('/') {
+        let valid = !part.is_empty()
+            && part != "."
+            && part != ".."
+            && !part.contains(['\\', ':'])
+            && !part.chars().any(char::is_control)
+            && !is_reserved(part);
+        if !valid {
+            return None;
+        }
+        path.push(part);
+    }
+    // guard against anything `push` still reads as absolute or as a prefix
+    path.components()
+        .all(|c| matches!(c, Component::Normal(_)))
+        .then_some(path)
+}
+
+/// Whether Windows reads `part` as a device like `CON` or `COM1`, whatever its extension.
+fn is_reserved(part: &str) -> bool {
+    let stem = part.split('.').next().unwrap_or(part).trim_end().to_ascii_uppercase();
+    match stem.as_bytes() {
+        b"CON" | b"PRN" | b"AUX" | b"NUL" => true,
+        [b'C', b'O', b'M', n] | [b'L', b'P', b'T', n] => (b'1'..=b'9').contains(n),
+        _ => false,
+    }
+}
+
+/// First of `file (1).txt`, `file (2).txt`, ... that doesn't exist yet.
+fn free_path(path: &Path) -> PathBuf {
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    let extension = path
+        .extension()
+        .map(|e| format!(".{}", e.to_string_lossy()))
+        .unwrap_or_default();
+
+    (1..)
+        .map(|n| path.with_file_name(format!("{stem} ({n}){extension}")))
+        .find(|candidate| !candidate.exists())
+        .expect("ran out of numbers")
+}
+
+/// Hidden sibling of a file being exported, on the same filesystem so the final rename is
+/// atomic. Removed on drop unless it was moved into place, which also covers a cancelled
+/// download.
+struct PartFile {
+    path: PathBuf,
+}
+
+impl PartFile {
+    fn new(path: &Path) -> Self {
+        let name = path.file_name().unwrap_or_default().to_string_lossy();
+        Self {
+            path: path.with_file_name(format!(".{name}.fling-part")),
+        }
+    }
+
+    fn persist(mut self, path: &Path) -> Result<()> {
+        std::fs::rename(&self.path, path)
+            .with_context(|| format!("Error moving file into {}", path.display()))?;
+        self.path = PathBuf::new();
+        Ok(())
+    }
+}
+
+impl Drop for PartFile {
+    fn drop(&mut self) {
+        if !self.path.as_os_str().is_empty() {
+            std::fs::remove_file(&self.path).ok();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sanitize_keeps_nested_names() {
+        assert_eq!(sanitize_name("a.txt"), Some(PathBuf::from("a.txt")));
+        assert_eq!(
+            sanitize_name("photos/2024/a.jpg"),
+            Some(["photos", "2024", "a.jpg"].iter().collect())
+        );
+        assert_eq!(sanitize_name("console.log"), Some(PathBuf::from("console.log")));
+    }
+
+    #[test]
+    fn sanitize_rejects_traversal() {
+        for name in ["..", "../a", "a/../../b", "a/..", ".", "./a", "a/./b"] {
+            assert_eq!(sanitize_name(name), None, "{name}");
+        }
+    }
+
+    #[test]
+    fn sanitize_rejects_absolute_paths() {
+        for name in ["/etc/passwd", "C:/Windows/a.dll", "C:a", "//server/share/a"] {
+            assert_eq!(sanitize_name(name), None, "{name}");
+        }
+    }
+
+    #[test]
+    fn sanitize_rejects_windows_separators() {
+        for name in ["a\\b", "..\\a", "\\\\server\\share", "a/b\\..\\..\\c"] {
+            assert_eq!(sanitize_name(name), None, "{name}");
+        }
+    }
+
+    #[test]
+    fn sanitize_rejects_reserved_names() {
+        for name in ["CON", "nul", "aux.txt", "a/COM1", "lpt9.tar.gz", "PRN "] {
+            assert_eq!(sanitize_name(name), None, "{name}");
+        }
+        for name in ["COM0", "LPT10", "connect", "nullable.txt"] {
+            assert!(sanitize_name(name).is_some(), "{name}");
+        }
+    }
+
+    #[test]
+    fn sanitize_rejects_empty_names() {
+        for name in ["", "/", "a/", "a//b", "a\0b", "a\nb"] {
+            assert_eq!(sanitize_name(name), None, "{name:?}");
+        }
+    }
+
+    /// An empty folder of its own, so tests running side by side don't see each other's files.
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("fling-test-{}-{name}", std::process::id()));
+        std::fs::remove_dir_all(&dir).ok();
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn free_path_numbers_collisions() {
+        let dir = scratch_dir("free-path");
+        let path = dir.join("file.txt");
+        std::fs::write(&path, "").unwrap();
+
+        let first = free_path(&path);
+        assert_eq!(first, dir.join("file (1).txt"));
+        std::fs::write(&first, "").unwrap();
+        assert_eq!(free_path(&path), dir.join("file (2).txt"));
+
+        let notes = dir.join("notes");
+        std::fs::write(&notes, "").unwrap();
+        assert_eq!(free_path(&notes), dir.join("notes (1)"));
+
+        let archive = dir.join("a.tar.gz");
+        std::fs::write(&archive, "").unwrap();
+        assert_eq!(free_path(&archive), dir.join("a.tar (1).gz"));
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
 }
@@ -0,0 +1,53 @@
+use std::path::Path;
+
+use tracing_appender::{
+    non_blocking::WorkerGuard,
+    rolling::{RollingFileAppender, Rotation},
+};
+use tracing_subscriber::{prelude::*, EnvFilter};
+
+/// Days of logs kept, one file per day.
+const MAX_LOG_FILES: usize = 7;
+/// What gets logged unless `--log-level` or `FLING_LOG` say otherwise.
+const DEFAULT_FILTER: &str = "warn,fling_native=info";
+
+/// Logs to a file in `log_dir` that's rotated daily, so a failed transfer can be looked into
+/// after the fact. `level` is a filter like `debug` or `fling_native=trace,iroh=warn`, setting it
+/// also prints the logs to stderr.
+///
+/// The returned guard flushes the file when it's dropped, keep it for as long as the app runs.
+pub fn init(level: Option<&str>, log_dir: &Path) -> Option<WorkerGuard> {
+    let filter = EnvFilter::try_new(level.unwrap_or(DEFAULT_FILTER)).unwrap_or_else(|e| {
+        eprintln!("Invalid log level ({e}), using {DEFAULT_FILTER}");
+        EnvFilter::new(DEFAULT_FILTER)
+    });
+    // the CLI talks to the terminal itself, logs only show up there when asked for
+    let stderr = level.map(|_| tracing_subscriber::fmt::layer().with_writer(std::io::stderr));
+
+    let appender = RollingFileAppender::builder()
+        .rotation(Rotation::DAILY)
+        .filename_prefix("fling")
+        .filename_suffix("log")
+        .max_log_files(MAX_LOG_FILES)
+        .build(log_dir);
+    let (file, guard) = match appender {
+        Ok(appender) => {
+            let (writer, guard) = tracing_appender::non_blocking(appender);
+            let layer = tracing_subscriber::fmt::layer()
+                .with_writer(writer)
+                .with_ansi(false);
+            (Some(layer), Some(guard))
+        }
+        Err(e) => {
+            eprintln!("Can't write logs to {}: {e}", log_dir.display());
+            (None, None)
+        }
+    };
+
+    tracing_subscriber::registry()
+        .with(filter)
+        .with(stderr)
+        .with(file)
+        .init();
+    guard
+}
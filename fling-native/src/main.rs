@@ -1,299 +1,451 @@
-use std::{path::PathBuf, str::FromStr};
-
-use anyhow::{anyhow, Context, Result};
-use futures_util::{SinkExt, StreamExt};
-use iroh::protocol::Router;
-use iroh_blobs::{
-    api::remote::GetProgressItem, format::collection::Collection,
-    get::request::get_hash_seq_and_sizes, ticket::BlobTicket, BlobFormat,
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    str::FromStr,
+    sync::Arc,
+    time::{Duration, SystemTime, UNIX_EPOCH},
 };
+
+use anyhow::{anyhow, bail, Context, Result};
+use clap::Parser;
+use iroh::{EndpointId, SecretKey};
+use iroh_blobs::{ticket::BlobTicket, BlobFormat};
 use names::{Generator, Name};
 use rfd::AsyncFileDialog;
-use serde_json::{self};
 use shared::{
-    app_events::AppEvent, network::Network, ui_events::UIEvent,
-    websocket_messages::WebSocketMessage,
+    app_events::AppEvent,
+    identity,
+    network::Network,
+    settings::Settings,
+    transfer::{TransferId, TransferState},
+    ui_events::UIEvent,
+    websocket_messages::{room_name, Offer, WebSocketMessage, DEFAULT_ROOM, OFFER_TTL},
+};
+use tokio::{
+    sync::{mpsc, oneshot, watch, Semaphore},
+    task::AbortHandle,
 };
-use tokio::sync::mpsc;
-use tokio_tungstenite::{connect_async, tungstenite::Message};
+use tracing::{error, info, info_span, warn, Instrument};
 use ui::UI;
 
-use crate::iroh_node::IrohNode;
+use crate::{
+    connection::Connection,
+    iroh_node::{check_size, check_space, collect_files, Audience, IrohNode},
+    history::History,
+    pending::PendingDownloads,
+};
 
+mod cli;
+mod config;
+mod connection;
+mod history;
 mod iroh_node;
-
-const WS_URL: &str = "wss://fling-server.fly.dev/ws";
-
-macro_rules! try_or_continue {
-    ($result:expr, $tx:expr, $msg:expr) => {
-        match $result {
-            Ok(val) => val,
-            Err(e) => {
-                $tx.send(AppEvent::FatalError(anyhow!(e).context($msg)))
-                    .ok();
-                continue;
-            }
-        }
-    };
+mod logging;
+mod pending;
+
+#[derive(Parser)]
+#[command(name = "fling", version, about = "Peer-to-peer file transfer")]
+struct Cli {
+    /// Websocket url of the signaling server, e.g. wss://fling.example.com/ws
+    #[arg(long, env = "FLING_SERVER", global = true)]
+    server: Option<String>,
+    /// What to log, e.g. debug or fling_native=trace,iroh=warn. Logs always go to a daily file in
+    /// the data dir, setting this prints them as well
+    #[arg(long, env = "FLING_LOG", global = true)]
+    log_level: Option<String>,
+    /// Runs the GUI when omitted
+    #[command(subcommand)]
+    command: Option<cli::Command>,
 }
 
 pub struct NativeNetwork {
     tx: mpsc::UnboundedSender<AppEvent>,
     rx: mpsc::UnboundedReceiver<AppEvent>,
     to_ws: mpsc::UnboundedSender<UIEvent>,
+    /// fires once the websocket has been closed
+    closed: oneshot::Receiver<()>,
+    store_dir: PathBuf,
+    download_dir: PathBuf,
+    endpoint_id: String,
 }
 
 impl NativeNetwork {
-    fn new(nickname: String, download_dir: PathBuf) -> Self {
+    /// `secret_key` is the device's, it both names our endpoint and signs what we tell the
+    /// server, so peers can trust the endpoint id it hands out. `server` overrides the saved
+    /// server url for as long as we run, without ever ending up in the settings.
+    fn new(
+        store_dir: PathBuf,
+        download_dir: PathBuf,
+        settings: Settings,
+        server: Option<String>,
+        secret_key: SecretKey,
+    ) -> Self {
         let (tx, rx) = mpsc::unbounded_channel::<AppEvent>();
         let (to_ws, mut from_ui) = mpsc::unbounded_channel::<UIEvent>();
+        let (closed_tx, closed) = oneshot::channel();
 
+        let pending = PendingDownloads::load(&store_dir, tx.clone());
+        if pending.is_empty() {
+            // nothing left to resume, don't let the store grow forever
+            std::fs::remove_dir_all(&store_dir).ok();
+        }
+        pending.announce();
+        let pending = Arc::new(pending);
+        let history = History::new(&config::config_dir(), tx.clone());
+        history.announce();
+        let endpoint_id = identity::endpoint_id(&secret_key.to_bytes());
+
+        let store_dir_clone = store_dir.clone();
+        let download_dir_clone = download_dir.clone();
         let tx_clone = tx.clone();
-        let tx_clone_1 = tx.clone();
         tokio::spawn(async move {
-            let ws_init = async {
-                let ws_stream = connect_async(WS_URL)
-                    .await
-                    .context("WebSocket connection failed")?;
-
-                let (sender, receiver) = ws_stream.0.split();
-                Ok::<_, anyhow::Error>((sender, receiver))
+            let (ws_out, outgoing) = mpsc::unbounded_channel::<WebSocketMessage>();
+            let server_url = |settings: &Settings| {
+                server.clone().unwrap_or_else(|| settings.server_url.clone())
             };
+            let (server_url_tx, url) = watch::channel(server_url(&settings));
+            let mut connection =
+                Connection::new(url, outgoing, secret_key.clone(), tx_clone.clone());
 
-            let tx_clone = tx_clone.clone();
-            let download_dir_1 = download_dir.clone();
-            let iroh_init = async {
-                let iroh_node = IrohNode::new(download_dir_1, nickname)
-                    .await
-                    .context("Iroh node initialization failed")?;
-
-                let router = Router::builder(iroh_node.endpoint.clone())
-                    .accept(iroh_blobs::ALPN, iroh_node.blobs.clone())
-                    .spawn();
+            // runs on its own, so the settings (and with them the server url) can still be
+            // changed while the first server we try is unreachable
+            let tx_ready = tx_clone.clone();
+            tokio::spawn(async move {
+                let ws_stream = connection.connect().await;
+                tx_ready.send(AppEvent::ReadyToPublishUser).ok();
+                connection.run(ws_stream).await;
+                closed_tx.send(()).ok();
+            });
 
-                Ok::<_, anyhow::Error>((iroh_node, router))
+            let iroh_node = match IrohNode::new(store_dir_clone, secret_key, tx_clone.clone()).await {
+                Ok(iroh_node) => Arc::new(iroh_node),
+                Err(e) => {
+                    let e = e.context("Iroh node initialization failed");
+                    tx_clone.send(AppEvent::FatalError(e)).ok();
+                    return;
+                }
             };
 
-            tokio::spawn(async move {
-                match tokio::try_join!(ws_init, iroh_init) {
-                    Ok(((mut sender, mut receiver), (iroh_node, router))) => {
-                        // get ws msg
-                        let tx_clone_1 = tx_clone.clone();
-                        tokio::spawn(async move {
-                            loop {
-                                match receiver.next().await {
-                                    Some(Ok(msg)) => process_message(msg, tx_clone_1.clone()).await,
-                                    Some(Err(e)) => {
-                                        tx_clone_1
-                                            .send(AppEvent::FatalError(
-                                                anyhow!(e).context("WebSocket error"),
-                                            ))
-                                            .ok();
-                                        break;
-                                    }
-                                    None => {
-                                        tx_clone_1
-                                            .send(AppEvent::FatalError(anyhow!(
-                                                "WebSocket disconnected"
-                                            )))
-                                            .ok();
-                                        break;
-                                    }
-                                }
-                            }
+            // send ws msg
+            let download_dir = download_dir_clone;
+            let mut settings = settings;
+            // each transfer runs in its own task so it can be cancelled
+            let mut transfers = HashMap::<TransferId, AbortHandle>::new();
+            let mut max_transfers = settings.max_transfers.max(1);
+            let limit = Arc::new(Semaphore::new(max_transfers));
+            // the one we last asked to join, offers are signed for it
+            let mut room = DEFAULT_ROOM.to_string();
+
+            while let Some(ui_event) = from_ui.recv().await {
+                transfers.retain(|_, handle| !handle.is_finished());
+
+                match ui_event {
+                    UIEvent::PrepareFile {
+                        id,
+                        recipient,
+                        files,
+                    } => {
+                        let task = send_files(
+                            id,
+                            iroh_node.clone(),
+                            room.clone(),
+                            recipient,
+                            files.into_iter().map(|f| f.path().to_owned()).collect(),
+                            ws_out.clone(),
+                            tx_clone.clone(),
+                        );
+                        let handle = spawn_transfer(id, task, limit.clone(), tx_clone.clone());
+                        transfers.insert(id, handle);
+                    }
+                    UIEvent::DownloadFile { id, offer } => {
+                        let offer_id = offer.id;
+                        let download = download_files(
+                            id,
+                            iroh_node.clone(),
+                            offer,
+                            download_dir.clone(),
+                            settings.clone(),
+                            pending.clone(),
+                            tx_clone.clone(),
+                        );
+                        let ws_out = ws_out.clone();
+                        let task = async move {
+                            download.await?;
+                            // lets the sender know it can stop waiting on us
+                            ws_out
+                                .send(WebSocketMessage::TransferComplete { offer_id })
+                                .ok();
+                            Ok(())
+                        };
+                        let handle = spawn_transfer(id, task, limit.clone(), tx_clone.clone());
+                        transfers.insert(id, handle);
+                    }
+                    UIEvent::CancelTransfer(id) => {
+                        // whatever a send offered can't be fetched anymore
+                        let revoked = iroh_node.revoke(id);
+                        // aborting drops the task's temp tags and partial files
+                        let aborted = transfers.remove(&id).is_some_and(|handle| {
+                            let running = !handle.is_finished();
+                            handle.abort();
+                            running
                         });
-
-                        // send ws msg
-                        let tx_clone = tx_clone.clone();
-                        let download_dir = download_dir.clone();
-                        tokio::spawn(async move {
-                            while let Some(ui_event) = from_ui.recv().await {
-                                match ui_event {
-                                    UIEvent::PrepareFile { recipient, files } => {
-                                        let files = files
-                                            .into_iter()
-                                            .map(|f| f.path().to_owned())
-                                            .collect::<Vec<_>>();
-
-                                        tx_clone.send(AppEvent::ImportStart).ok();
-                                        let tt = try_or_continue!(
-                                            iroh_node.import(files, tx_clone.clone()).await,
-                                            tx_clone,
-                                            "Failed to import file(s)"
-                                        );
-                                        tx_clone.send(AppEvent::ImportDone).ok();
-                                        let ticket = BlobTicket::new(
-                                            iroh_node.endpoint.addr(),
-                                            tt.hash(),
-                                            BlobFormat::HashSeq,
-                                        )
-                                        .to_string();
-
-                                        let json = WebSocketMessage::SendFile { recipient, ticket }
-                                            .to_json();
-
-                                        if let Err(e) = sender
-                                            .send(Message::Text(json.into()))
-                                            .await
-                                            .context("Websocket send failed")
-                                        {
-                                            tx_clone.send(AppEvent::FatalError(e)).ok();
-                                        }
-                                    }
-                                    UIEvent::DownloadFile(ticket) => {
-                                        let ticket = match BlobTicket::from_str(&ticket) {
-                                            Ok(ticket) => ticket,
-                                            Err(e) => {
-                                                tx_clone
-                                                    .send(AppEvent::FatalError(
-                                                        anyhow!(e).context(
-                                                            "Failed parsing to blob ticket",
-                                                        ),
-                                                    ))
-                                                    .ok();
-                                                continue;
-                                            }
-                                        };
-
-                                        let downloader =
-                                            iroh_node.store.downloader(&iroh_node.endpoint);
-                                        match downloader
-                                            .download(ticket.hash(), Some(ticket.addr().id))
-                                            .await
-                                        {
-                                            Ok(_) => {
-                                                let download_dir = download_dir.clone();
-                                                let local_info = try_or_continue!(
-                                                    iroh_node
-                                                        .store
-                                                        .remote()
-                                                        .local(ticket.hash_and_format())
-                                                        .await,
-                                                    tx_clone,
-                                                    "Failed to get local info"
-                                                );
-
-                                                if !local_info.is_complete() {
-                                                    let connection = try_or_continue!(
-                                                        iroh_node
-                                                            .endpoint
-                                                            .connect(
-                                                                ticket.addr().id,
-                                                                iroh_blobs::ALPN
-                                                            )
-                                                            .await,
-                                                        tx_clone,
-                                                        "Failed to connect to sender"
-                                                    );
-                                                    let (_, size) = try_or_continue!(
-                                                        get_hash_seq_and_sizes(
-                                                            &connection,
-                                                            &ticket.hash(),
-                                                            1024 * 1024 * 1024 * 5, // 5gb
-                                                            None
-                                                        )
-                                                        .await,
-                                                        tx_clone,
-                                                        "Failed to get file(s) size"
-                                                    );
-
-                                                    // skip the 1st index, bcs its the collection blob and obv we don't need it
-                                                    let actual_size =
-                                                        size.iter().skip(1).sum::<u64>();
-                                                    let get = iroh_node.store.remote().execute_get(
-                                                        connection,
-                                                        local_info.missing(),
-                                                    );
-                                                    let mut stream = get.stream();
-                                                    tx_clone.send(AppEvent::DownloadStart).ok();
-                                                    while let Some(item) = stream.next().await {
-                                                        match item {
-                                                            GetProgressItem::Progress(b) => {
-                                                                let value =
-                                                                    b as f32 / actual_size as f32;
-                                                                tx_clone.send(AppEvent::UpdateProgressValue(value)).ok();
-                                                            }
-                                                            GetProgressItem::Done(_) => {
-                                                                tx_clone
-                                                                    .send(AppEvent::DownloadDone)
-                                                                    .ok();
-                                                                break;
-                                                            }
-                                                            GetProgressItem::Error(e) => {
-                                                                tx_clone.send(AppEvent::FatalError(anyhow!(e).context("Error downloading one of the files"))).ok();
-                                                            }
-                                                        }
-                                                    }
-                                                }
-
-                                                match Collection::load(
-                                                    ticket.hash(),
-                                                    iroh_node.store.as_ref(),
-                                                )
-                                                .await
-                                                {
-                                                    Ok(c) => {
-                                                        for (f, h) in c.into_iter() {
-                                                            let p = download_dir.join(f);
-                                                            if let Err(e) = iroh_node
-                                                                .store
-                                                                .blobs()
-                                                                .export(h, p)
-                                                                .await
-                                                            {
-                                                                tx_clone.send(AppEvent::FatalError(anyhow!(e).context("Error downloading file {p}: {e:?}")))
-                                                                    .ok();
-                                                            }
-                                                        }
-                                                    }
-                                                    Err(e) => {
-                                                        tx_clone
-                                                            .send(AppEvent::FatalError(e.context(
-                                                                "Error loading collection",
-                                                            )))
-                                                            .ok();
-                                                    }
-                                                }
-                                            }
-                                            Err(e) => {
-                                                tx_clone
-                                                    .send(AppEvent::FatalError(
-                                                        e.context("Download failed"),
-                                                    ))
-                                                    .ok();
-                                            }
-                                        }
-
-                                        let _ = &router;
-                                    }
-                                    _ => {
-                                        let websocket_msg =
-                                            ui_event.to_ws().expect("shouldn't happen");
-                                        let json = websocket_msg.to_json();
-                                        if let Err(e) = sender
-                                            .send(Message::Text(json.into()))
-                                            .await
-                                            .context("Websocket send failed")
-                                        {
-                                            tx_clone.send(AppEvent::FatalError(e)).ok();
-                                        }
-                                    }
-                                }
+                        if revoked || aborted {
+                            info!(transfer = id, "cancelled");
+                            tx_clone
+                                .send(AppEvent::TransferUpdate(id, TransferState::Cancelled))
+                                .ok();
+                        }
+                    }
+                    UIEvent::GrantAccess { id, endpoint_id } => {
+                        match EndpointId::from_str(&endpoint_id) {
+                            Ok(endpoint_id) => iroh_node.allow(id, Audience::Peer(endpoint_id)),
+                            Err(e) => {
+                                let error = format!("Recipient sent a bad endpoint id: {e}");
+                                warn!(transfer = id, "{error}");
+                                tx_clone
+                                    .send(AppEvent::TransferUpdate(
+                                        id,
+                                        TransferState::Failed(error),
+                                    ))
+                                    .ok();
                             }
+                        }
+                    }
+                    UIEvent::DiscardDownload(ticket) => {
+                        pending.remove(&ticket);
+                    }
+                    UIEvent::RecordHistory(entry) => history.record(entry),
+                    UIEvent::UpdateSettings(new_settings) => {
+                        if let Err(e) = config::save_settings(&new_settings) {
+                            tx_clone.send(AppEvent::FatalError(e)).ok();
+                        }
+                        let max = new_settings.max_transfers.max(1);
+                        resize_limit(&limit, max_transfers, max);
+                        max_transfers = max;
+                        // the connection reconnects on its own when the url changes
+                        let new_url = server_url(&new_settings);
+                        server_url_tx.send_if_modified(|url| {
+                            let changed = *url != new_url;
+                            *url = new_url;
+                            changed
                         });
+                        settings = new_settings;
                     }
-                    Err(e) => {
-                        let context = format!("Join handle error {:?}", e);
-                        tx_clone.send(AppEvent::FatalError(e.context(context))).ok();
+                    ui_event @ (UIEvent::JoinRoom { .. } | UIEvent::RespondOffer { .. }) => {
+                        if let UIEvent::JoinRoom { room: joined, .. } = &ui_event {
+                            room = room_name(joined).to_string();
+                        }
+                        let Some(websocket_msg) = ui_event.to_ws() else {
+                            continue;
+                        };
+                        if let Err(e) = ws_out.send(websocket_msg).context("Websocket send failed")
+                        {
+                            tx_clone.send(AppEvent::FatalError(e)).ok();
+                        }
                     }
                 }
+            }
+        });
 
-                tx_clone_1.send(AppEvent::ReadyToPublishUser).ok();
-            });
+        Self {
+            tx,
+            rx,
+            to_ws,
+            closed,
+            store_dir,
+            download_dir,
+            endpoint_id,
+        }
+    }
+
+    /// Waits for the next event, for front-ends that don't poll every frame like the UI does.
+    pub async fn recv(&mut self) -> Option<AppEvent> {
+        self.rx.recv().await
+    }
+
+    /// Leaves the server cleanly, giving up after a second if the socket is stuck.
+    pub async fn shutdown(self) {
+        let Self { to_ws, closed, .. } = self;
+        drop(to_ws);
+        tokio::time::timeout(Duration::from_secs(1), closed).await.ok();
+    }
+}
+
+/// Queues a transfer until one of the `limit` slots frees up and runs it in the background,
+/// reporting it as failed if it errors. Aborting the returned handle cancels it.
+fn spawn_transfer(
+    id: TransferId,
+    task: impl Future<Output = Result<()>> + Send + 'static,
+    limit: Arc<Semaphore>,
+    tx: mpsc::UnboundedSender<AppEvent>,
+) -> AbortHandle {
+    tx.send(AppEvent::TransferUpdate(id, TransferState::Queued))
+        .ok();
+    // everything the transfer logs carries its id
+    let span = info_span!("transfer", id);
+    tokio::spawn(
+        async move {
+            let Ok(_permit) = limit.acquire_owned().await else {
+                return;
+            };
+            match task.await {
+                Ok(()) => info!("finished"),
+                Err(e) => {
+                    error!("failed: {e:#}");
+                    let state = TransferState::Failed(format!("{e:#}"));
+                    tx.send(AppEvent::TransferUpdate(id, state)).ok();
+                }
+            }
+        }
+        .instrument(span),
+    )
+    .abort_handle()
+}
+
+/// Grows or shrinks the number of transfer slots. Shrinking takes effect as running transfers
+/// finish, nothing already going is stopped.
+fn resize_limit(limit: &Arc<Semaphore>, from: usize, to: usize) {
+    if to > from {
+        limit.add_permits(to - from);
+    } else if to < from {
+        let limit = limit.clone();
+        tokio::spawn(async move {
+            if let Ok(permits) = limit.acquire_many_owned((from - to) as u32).await {
+                permits.forget();
+            }
         });
+    }
+}
+
+/// Imports `files` and offers them to `recipient` in `room`.
+async fn send_files(
+    id: TransferId,
+    iroh_node: Arc<IrohNode>,
+    room: String,
+    recipient: String,
+    files: Vec<PathBuf>,
+    ws_out: mpsc::UnboundedSender<WebSocketMessage>,
+    tx: mpsc::UnboundedSender<AppEvent>,
+) -> Result<()> {
+    let picked = files.clone();
+    let files = collect_files(files).context("Failed to read file(s)")?;
+    let names = files
+        .iter()
+        .map(|(name, _)| name.clone())
+        .collect::<Vec<_>>();
+    let size = files
+        .iter()
+        .filter_map(|(_, p)| std::fs::metadata(p).ok())
+        .map(|m| m.len())
+        .sum::<u64>();
+    info!(%recipient, files = names.len(), size, "importing");
+
+    tx.send(AppEvent::TransferUpdate(id, TransferState::Importing))
+        .ok();
+    let tt = iroh_node
+        .import(id, files, tx.clone())
+        .await
+        .context("Failed to import file(s)")?;
+    iroh_node.expire(id, OFFER_TTL)?;
+    tx.send(AppEvent::TransferDetails(id, tt.hash().to_string(), picked))
+        .ok();
+    info!(hash = %tt.hash(), "offering");
+
+    let ticket =
+        BlobTicket::new(iroh_node.endpoint.addr(), tt.hash(), BlobFormat::HashSeq).to_string();
+    let nonce = rand::random();
+    let signed_at = unix_millis();
+    let signature = identity::sign(
+        &iroh_node.endpoint.secret_key().to_bytes(),
+        &identity::offer_payload(&room, &recipient, nonce, signed_at, &ticket, &names, size),
+    );
+    tx.send(AppEvent::Offered(id, nonce)).ok();
+    ws_out
+        .send(WebSocketMessage::SendFile {
+            recipient,
+            nonce,
+            signed_at,
+            ticket,
+            files: names,
+            size,
+            signature,
+        })
+        .context("Websocket send failed")?;
+    tx.send(AppEvent::TransferUpdate(id, TransferState::Offering))
+        .ok();
+
+    Ok(())
+}
+
+/// Downloads an accepted offer. It stays in `pending` until every file is exported, so a
+/// download that fails or gets cancelled can be resumed later.
+async fn download_files(
+    id: TransferId,
+    iroh_node: Arc<IrohNode>,
+    offer: Offer,
+    download_dir: PathBuf,
+    settings: Settings,
+    pending: Arc<PendingDownloads>,
+    tx: mpsc::UnboundedSender<AppEvent>,
+) -> Result<()> {
+    let ticket = BlobTicket::from_str(&offer.ticket)
+        .map_err(|e| anyhow!(e).context("Failed parsing to blob ticket"))?;
+    check_sender(&offer, &ticket)?;
+    // the announced size is only the sender's word, the real one gets checked again before
+    // anything is fetched
+    check_size(
+        offer.size,
+        settings.max_download_size,
+        &iroh_node.store_dir,
+        &download_dir,
+    )?;
+    info!(
+        sender = %offer.sender,
+        hash = %ticket.hash(),
+        files = offer.files.len(),
+        size = offer.size,
+        "downloading"
+    );
+
+    pending.insert(&offer);
+    let saved = iroh_node
+        .download(id, &ticket, &download_dir, &settings, tx.clone())
+        .await
+        .context("Download failed")?;
+    pending.remove(&offer.ticket);
+    info!(files = saved.len(), dir = %download_dir.display(), "saved");
+    tx.send(AppEvent::TransferDetails(id, ticket.hash().to_string(), saved))
+        .ok();
+
+    tx.send(AppEvent::TransferUpdate(id, TransferState::Done))
+        .ok();
+    Ok(())
+}
+
+/// Milliseconds since the unix epoch, what offers are timestamped in.
+fn unix_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or_default()
+}
 
-        Self { tx, rx, to_ws }
+/// Makes sure the offer was signed by the endpoint its ticket points at, so a server that
+/// tampers with offers can't make us fetch from somewhere else.
+fn check_sender(offer: &Offer, ticket: &BlobTicket) -> Result<()> {
+    offer
+        .verify()
+        .map_err(|e| anyhow!("Offer from {} isn't signed by them: {e}", offer.sender))?;
+    let sender_id = EndpointId::from_str(&offer.sender_id)
+        .map_err(|e| anyhow!(e).context("Offer has a bad sender id"))?;
+    if ticket.addr().id != sender_id {
+        bail!(
+            "Offer from {} points at another endpoint than the one that signed it",
+            offer.sender
+        );
     }
+    Ok(())
 }
 
 impl Network for NativeNetwork {
@@ -318,10 +470,69 @@ impl Network for NativeNetwork {
             };
         });
     }
+
+    fn open_folder_dialog(&mut self) {
+        let tx = self.tx.clone();
+        tokio::spawn(async move {
+            let folders = AsyncFileDialog::new().pick_folders().await;
+            if let Some(file_handles) = folders {
+                tx.send(AppEvent::ReceivedFile(file_handles)).ok();
+            };
+        });
+    }
+
+    fn show_in_folder(&self, path: &Path) {
+        let folder = path.parent().unwrap_or(path);
+        let program = if cfg!(target_os = "macos") {
+            "open"
+        } else if cfg!(windows) {
+            "explorer"
+        } else {
+            "xdg-open"
+        };
+        match std::process::Command::new(program).arg(folder).spawn() {
+            Ok(mut child) => {
+                // reaped in the background so it doesn't linger as a zombie
+                std::thread::spawn(move || child.wait());
+            }
+            Err(e) => {
+                let context = format!("Failed to open {}", folder.display());
+                self.tx.send(AppEvent::FatalError(anyhow!(e).context(context))).ok();
+            }
+        }
+    }
+
+    fn check_space(&self, size: u64) -> Result<()> {
+        check_space(size, &self.store_dir, &self.download_dir)
+    }
+
+    fn endpoint_id(&self) -> String {
+        self.endpoint_id.clone()
+    }
 }
 
 #[tokio::main]
-async fn main() -> eframe::Result {
+async fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let _log_guard = logging::init(cli.log_level.as_deref(), &config::data_dir().join("logs"));
+    let mut settings = config::load_settings();
+    if settings.nickname.is_empty() {
+        // picked once and kept, so others keep seeing the same name
+        let mut generator = Generator::with_naming(Name::Numbered);
+        settings.nickname = generator.next().unwrap_or("Guest".into());
+        if let Err(e) = config::save_settings(&settings) {
+            eprintln!("{e:#}");
+        }
+    }
+    let secret_key = config::load_secret_key().unwrap_or_else(|e| {
+        eprintln!("{e:#}, using a key that only lasts until exit");
+        SecretKey::generate(&mut rand::rng())
+    });
+
+    if let Some(command) = cli.command {
+        return cli::run(command, settings, cli.server, secret_key).await;
+    }
+
     let native_options = eframe::NativeOptions {
         viewport: egui::ViewportBuilder::default()
             .with_resizable(true)
@@ -330,56 +541,33 @@ async fn main() -> eframe::Result {
         ..Default::default()
     };
 
-    let mut generator = Generator::with_naming(Name::Numbered);
-    let nickname = generator.next().unwrap_or("Guest".into());
     let download_dir = dirs::download_dir().unwrap_or_else(|| PathBuf::from("."));
 
-    let native_network = NativeNetwork::new(nickname.clone(), download_dir.clone());
+    let native_network = NativeNetwork::new(
+        config::data_dir().join("store"),
+        download_dir.clone(),
+        settings.clone(),
+        cli.server,
+        secret_key,
+    );
 
-    eframe::run_native(
+    let result = eframe::run_native(
         "Fling",
         native_options,
         Box::new(|cc| {
             Ok(Box::new(UI::new(
                 cc,
-                nickname,
                 download_dir,
+                settings,
                 native_network,
             )))
         }),
-    )
-}
-
-async fn process_message(msg: Message, tx: mpsc::UnboundedSender<AppEvent>) {
-    if let Message::Text(bytes) = msg {
-        match serde_json::from_str::<WebSocketMessage>(bytes.as_str()) {
-            Ok(websocket_msg) => match websocket_msg {
-                WebSocketMessage::RegisterSuccess(current_users) => {
-                    tx.send(AppEvent::RegisterSuccess(current_users)).ok();
-                }
-                WebSocketMessage::UserJoined(nickname) => {
-                    tx.send(AppEvent::AddNewUser(nickname)).ok();
-                }
-                WebSocketMessage::UserLeft(nickname) => {
-                    tx.send(AppEvent::RemoveUser(nickname)).ok();
-                }
-                WebSocketMessage::ReceiveFile(ticket) => {
-                    tx.send(AppEvent::DownloadFile(ticket)).ok();
-                }
-                WebSocketMessage::ErrorDeserializingJson(e) => {
-                    tx.send(AppEvent::FatalError(
-                        anyhow!(e).context("Server JSON error"),
-                    ))
-                    .ok();
-                }
-                _ => {}
-            },
-            Err(e) => {
-                tx.send(AppEvent::FatalError(
-                    anyhow!(e).context("Message parse failed"),
-                ))
-                .ok();
-            }
+    );
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{e}");
+            ExitCode::FAILURE
         }
     }
 }
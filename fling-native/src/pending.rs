@@ -0,0 +1,78 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use anyhow::{Context, Result};
+use shared::{app_events::AppEvent, websocket_messages::Offer};
+use tokio::sync::mpsc::UnboundedSender;
+
+/// Downloads that were started but never finished, mirrored to disk so they survive restarts.
+///
+/// The list lives next to the blobs it's made of, so the two go away together.
+pub struct PendingDownloads {
+    path: PathBuf,
+    offers: Mutex<Vec<Offer>>,
+    tx: UnboundedSender<AppEvent>,
+}
+
+impl PendingDownloads {
+    pub fn load(store_dir: &Path, tx: UnboundedSender<AppEvent>) -> Self {
+        let path = store_dir.join("pending.json");
+        let offers = fs::read_to_string(&path)
+            .ok()
+            .and_then(|s| serde_json::from_str(&s).ok())
+            .unwrap_or_default();
+
+        Self {
+            path,
+            offers: Mutex::new(offers),
+            tx,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.offers.lock().unwrap().is_empty()
+    }
+
+    pub fn insert(&self, offer: &Offer) {
+        let mut offers = self.offers.lock().unwrap();
+        if !offers.iter().any(|o| o.ticket == offer.ticket) {
+            offers.push(offer.clone());
+            self.publish(&offers);
+        }
+    }
+
+    pub fn remove(&self, ticket: &str) {
+        let mut offers = self.offers.lock().unwrap();
+        offers.retain(|o| o.ticket != ticket);
+        self.publish(&offers);
+    }
+
+    /// Shows the current list to the UI.
+    pub fn announce(&self) {
+        let offers = self.offers.lock().unwrap();
+        self.tx
+            .send(AppEvent::PendingDownloads(offers.clone()))
+            .ok();
+    }
+
+    /// Saves the list and shows it to the UI.
+    fn publish(&self, offers: &[Offer]) {
+        if let Err(e) = self.save(offers) {
+            self.tx.send(AppEvent::FatalError(e)).ok();
+        }
+        self.tx
+            .send(AppEvent::PendingDownloads(offers.to_vec()))
+            .ok();
+    }
+
+    fn save(&self, offers: &[Offer]) -> Result<()> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir).context("Failed to create store dir")?;
+        }
+        let json = serde_json::to_string_pretty(offers)?;
+        fs::write(&self.path, json).context("Failed to write pending downloads")
+    }
+}
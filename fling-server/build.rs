@@ -0,0 +1,78 @@
+//! Embeds the browser client, Trunk's `dist` output, into the server binary.
+//!
+//! The files are taken from `../fling-wasm/dist`, or from `FLING_WEB_DIST` if it's set. Without
+//! them the server still builds, it just doesn't serve a client. Same for a `dist` without the
+//! compiled `.wasm`, a client that can't start is worse than none.
+
+use std::{
+    env,
+    fmt::Write as _,
+    fs,
+    path::{Path, PathBuf},
+};
+
+fn main() {
+    println!("cargo:rerun-if-env-changed=FLING_WEB_DIST");
+    let dist = env::var_os("FLING_WEB_DIST")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| {
+            Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("../fling-wasm/dist")
+        });
+    // watching a path that doesn't exist reruns this on every build, so until there's a `dist`
+    // its parent is watched for it to show up
+    if dist.exists() {
+        println!("cargo:rerun-if-changed={}", dist.display());
+    } else if let Some(parent) = dist.parent().filter(|parent| parent.exists()) {
+        println!("cargo:rerun-if-changed={}", parent.display());
+    }
+
+    let mut files = Vec::new();
+    collect(&dist, &dist, &mut files);
+    files.sort();
+    if !files.is_empty() && !files.iter().any(|(path, _)| path.ends_with(".wasm")) {
+        println!(
+            "cargo:warning=no .wasm in {}, not embedding the browser client. Run `trunk build` \
+             in fling-wasm first",
+            dist.display()
+        );
+        files.clear();
+    }
+
+    let mut out = String::from("pub static ASSETS: &[Asset] = &[\n");
+    for (path, file) in files {
+        let body = fs::read(&file).unwrap();
+        writeln!(
+            out,
+            "    Asset {{ path: {path:?}, etag: \"\\\"{:016x}\\\"\", body: include_bytes!({:?}) }},",
+            fnv1a(&body),
+            file.canonicalize().unwrap()
+        )
+        .unwrap();
+    }
+    out.push_str("];\n");
+
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    fs::write(out_dir.join("web_assets.rs"), out).unwrap();
+}
+
+/// Every file under `dir`, with its path relative to `root` in url form.
+fn collect(root: &Path, dir: &Path, files: &mut Vec<(String, PathBuf)>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            collect(root, &path, files);
+        } else if let Some(relative) = path.strip_prefix(root).ok().and_then(|p| p.to_str()) {
+            files.push((relative.replace('\\', "/"), path.clone()));
+        }
+    }
+}
+
+/// Cheap content hash for the ETags, it only has to change when the file does.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &b| {
+        (hash ^ b as u64).wrapping_mul(0x0100_0000_01b3)
+    })
+}
@@ -1,4 +1,9 @@
-use std::{env, net::SocketAddr, sync::Arc};
+use std::{
+    env,
+    net::SocketAddr,
+    sync::{atomic::Ordering, Arc},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
 
 use anyhow::Result;
 use axum::{
@@ -6,45 +11,57 @@ use axum::{
         ws::{Message, WebSocket},
         ConnectInfo, State, WebSocketUpgrade,
     },
-    response::IntoResponse,
-    routing::{any, Router},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::{any, get, Router},
 };
 use axum_extra::{headers::UserAgent, TypedHeader};
-use dashmap::DashMap;
 use futures_util::{
     stream::{SplitSink, SplitStream},
     SinkExt, StreamExt,
 };
-use shared::websocket_messages::WebSocketMessage;
+use shared::{
+    identity,
+    websocket_messages::{room_name, Offer, WebSocketMessage, DEFAULT_ROOM},
+};
 use tokio::{
     net::TcpListener,
     sync::{
-        broadcast,
+        broadcast::{self, error::RecvError},
         mpsc::{self, Receiver, Sender},
     },
+    task::JoinHandle,
+    time::{timeout, MissedTickBehavior},
 };
+use tracing::{debug, field, info, info_span, warn, Instrument, Span};
+use tracing_subscriber::EnvFilter;
 
-#[derive(Clone)]
-struct AppState {
-    users_list: Arc<DashMap<String, Sender<WebSocketMessage>>>,
-    broadcast_tx: tokio::sync::broadcast::Sender<WebSocketMessage>,
-}
+use crate::{
+    metrics::Metrics,
+    state::{AppState, PendingOffer, Session, Timeouts, MAX_NICKNAME_LEN},
+};
 
-impl AppState {
-    fn new() -> Self {
-        let (broadcast_tx, _) = broadcast::channel::<WebSocketMessage>(100);
-        Self {
-            users_list: Arc::new(DashMap::new()),
-            broadcast_tx,
-        }
-    }
-}
+mod metrics;
+mod state;
+mod web;
 
 #[tokio::main]
 async fn main() -> Result<()> {
+    // RUST_LOG picks what gets logged, e.g. `debug` or `info,fling_server=debug`
+    tracing_subscriber::fmt()
+        .with_env_filter(
+            EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info")),
+        )
+        .init();
+
+    let state = AppState::new(Timeouts::from_env());
     let app = Router::new()
         .route("/ws", any(ws_handler))
-        .with_state(AppState::new());
+        .route("/metrics", get(metrics::serve))
+        .route("/healthz", get(healthz))
+        .route("/readyz", get(readyz))
+        .fallback(web::serve)
+        .with_state(state.clone());
 
     let host = env::var("HOST").unwrap_or_else(|_| "0.0.0.0".to_string());
     let port = env::var("PORT").unwrap_or_else(|_| "8080".to_string());
@@ -52,47 +69,152 @@ async fn main() -> Result<()> {
 
     let listener = TcpListener::bind(&addr).await.unwrap();
 
-    println!("server running at {:?}", listener.local_addr());
+    info!(addr = ?listener.local_addr(), "server running");
+    if !web::is_bundled() {
+        warn!("no browser client bundled, build fling-wasm with trunk to serve it");
+    }
 
     axum::serve(
         listener,
         app.into_make_service_with_connect_info::<SocketAddr>(),
     )
+    .with_graceful_shutdown(shutdown_signal(state.clone()))
     .await?;
 
+    // upgraded sockets aren't tracked by axum, give clients a chance to leave on their own
+    let drained = timeout(state.timeouts.shutdown_deadline, async {
+        while state.metrics.connected_sockets.load(Ordering::Relaxed) > 0 {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+    })
+    .await;
+    if drained.is_err() {
+        warn!(
+            "closing {} socket(s) still open after the shutdown deadline",
+            state.metrics.connected_sockets.load(Ordering::Relaxed)
+        );
+    }
+
     Ok(())
 }
 
+/// Resolves on ctrl-c or SIGTERM, after which new sockets are refused and the open ones are told
+/// to reconnect elsewhere.
+async fn shutdown_signal(state: AppState) {
+    let ctrl_c = async {
+        tokio::signal::ctrl_c().await.ok();
+    };
+    #[cfg(unix)]
+    let terminate = async {
+        match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate()) {
+            Ok(mut signal) => {
+                signal.recv().await;
+            }
+            Err(_) => std::future::pending().await,
+        }
+    };
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {},
+        _ = terminate => {},
+    }
+
+    info!("shutting down, draining open sockets");
+    state.shutting_down.send_replace(true);
+}
+
+async fn healthz() -> &'static str {
+    "ok"
+}
+
+/// Fails once the server is shutting down, so no new clients get routed here.
+async fn readyz(State(state): State<AppState>) -> impl IntoResponse {
+    if *state.shutting_down.borrow() {
+        (StatusCode::SERVICE_UNAVAILABLE, "shutting down")
+    } else {
+        (StatusCode::OK, "ok")
+    }
+}
+
 async fn ws_handler(
     ws: WebSocketUpgrade,
-    _user_agent: Option<TypedHeader<UserAgent>>,
+    user_agent: Option<TypedHeader<UserAgent>>,
     ConnectInfo(addr): ConnectInfo<SocketAddr>,
     State(state): State<AppState>,
-) -> impl IntoResponse {
-    println!("{addr} connected");
+) -> Response {
+    if *state.shutting_down.borrow() {
+        return StatusCode::SERVICE_UNAVAILABLE.into_response();
+    }
+
+    let id = state.next_connection_id.fetch_add(1, Ordering::Relaxed);
+    let user_agent = user_agent
+        .map(|TypedHeader(user_agent)| user_agent.to_string())
+        .unwrap_or_default();
+    // everything logged for this socket carries these, the last two once the client tells us
+    let span = info_span!(
+        "conn",
+        id,
+        peer = %addr,
+        user_agent = %user_agent,
+        endpoint = field::Empty,
+        nickname = field::Empty,
+    );
 
-    ws.on_failed_upgrade(|e| {
-        println!("error upgrading ws: {:?}", e);
+    let failed_span = span.clone();
+    ws.on_failed_upgrade(move |e| {
+        warn!(parent: &failed_span, "error upgrading ws: {e}");
+    })
+    .on_upgrade(move |socket| {
+        state
+            .metrics
+            .connected_sockets
+            .fetch_add(1, Ordering::Relaxed);
+        handle_socket(socket, state).instrument(span)
     })
-    .on_upgrade(move |socket| handle_socket(socket, state))
 }
 
 async fn handle_socket(socket: WebSocket, state: AppState) {
+    info!("connected");
     let (sender, receiver) = socket.split();
     let (tx, rx) = mpsc::channel::<WebSocketMessage>(100);
 
-    let broadcast_rx = state.broadcast_tx.subscribe();
+    let nonce = uuid::Uuid::new_v4().to_string();
+    tx.send(WebSocketMessage::Challenge {
+        nonce: nonce.clone(),
+    })
+    .await
+    .ok();
 
-    tokio::spawn(write(sender, rx));
-    tokio::spawn(read(receiver, tx.clone(), state));
-    tokio::spawn(broadcast_read(broadcast_rx, tx));
+    tokio::spawn(
+        write(
+            sender,
+            rx,
+            state.timeouts.ping_interval,
+            state.metrics.clone(),
+        )
+        .in_current_span(),
+    );
+    tokio::spawn(read(receiver, tx, state, nonce).in_current_span());
 }
 
 async fn broadcast_read(
-    mut broadcast_rx: tokio::sync::broadcast::Receiver<WebSocketMessage>,
-    tx: tokio::sync::mpsc::Sender<WebSocketMessage>,
+    mut broadcast_rx: broadcast::Receiver<WebSocketMessage>,
+    tx: Sender<WebSocketMessage>,
+    metrics: Arc<Metrics>,
 ) {
-    while let Ok(websocket_msg) = broadcast_rx.recv().await {
+    loop {
+        let websocket_msg = match broadcast_rx.recv().await {
+            Ok(websocket_msg) => websocket_msg,
+            Err(RecvError::Lagged(skipped)) => {
+                // the skipped updates are gone, carry on with the ones after them
+                warn!(skipped, "presence updates lagged behind");
+                metrics.broadcast_lagged.fetch_add(1, Ordering::Relaxed);
+                continue;
+            }
+            Err(RecvError::Closed) => break,
+        };
         match websocket_msg {
             WebSocketMessage::UserJoined(nickname) => {
                 tx.send(WebSocketMessage::UserJoined(nickname)).await.ok();
@@ -105,13 +227,44 @@ async fn broadcast_read(
     }
 }
 
-async fn write(mut sender: SplitSink<WebSocket, Message>, mut rx: Receiver<WebSocketMessage>) {
-    while let Some(msg) = rx.recv().await {
+async fn write(
+    mut sender: SplitSink<WebSocket, Message>,
+    mut rx: Receiver<WebSocketMessage>,
+    ping_interval: Duration,
+    metrics: Arc<Metrics>,
+) {
+    let mut ping = tokio::time::interval(ping_interval);
+    ping.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    // the first tick completes immediately, no need to ping a socket that just connected
+    ping.tick().await;
+
+    loop {
+        let msg = tokio::select! {
+            msg = rx.recv() => match msg {
+                Some(msg) => msg,
+                None => break,
+            },
+            _ = ping.tick() => {
+                if let Err(e) = sender.send(Message::Ping(Default::default())).await {
+                    debug!("ping failed: {e}");
+                    break;
+                }
+                continue;
+            }
+        };
+
+        metrics.message("out", msg.kind());
         match msg {
-            WebSocketMessage::RegisterSuccess(current_users) => {
+            msg @ (WebSocketMessage::Challenge { .. }
+            | WebSocketMessage::RegisterSuccess { .. }
+            | WebSocketMessage::ResumeFailed
+            | WebSocketMessage::ServerShuttingDown { .. }) => {
+                sender.send(Message::Text(msg.to_json().into())).await.ok();
+            }
+            WebSocketMessage::RegisterRejected { reason } => {
                 sender
                     .send(Message::Text(
-                        WebSocketMessage::RegisterSuccess(current_users)
+                        WebSocketMessage::RegisterRejected { reason }
                             .to_json()
                             .into(),
                     ))
@@ -134,13 +287,11 @@ async fn write(mut sender: SplitSink<WebSocket, Message>, mut rx: Receiver<WebSo
                     .await
                     .ok();
             }
-            WebSocketMessage::ReceiveFile(ticket) => {
-                sender
-                    .send(Message::Text(
-                        WebSocketMessage::ReceiveFile(ticket).to_json().into(),
-                    ))
-                    .await
-                    .ok();
+            msg @ (WebSocketMessage::IncomingOffer(_)
+            | WebSocketMessage::OfferAccepted { .. }
+            | WebSocketMessage::OfferDeclined { .. }
+            | WebSocketMessage::Delivered { .. }) => {
+                sender.send(Message::Text(msg.to_json().into())).await.ok();
             }
 
             // errors
@@ -157,60 +308,374 @@ async fn write(mut sender: SplitSink<WebSocket, Message>, mut rx: Receiver<WebSo
     }
 }
 
-async fn read(mut receiver: SplitStream<WebSocket>, tx: Sender<WebSocketMessage>, state: AppState) {
-    let mut current_username = String::new();
-    while let Some(Ok(msg)) = receiver.next().await {
-        match msg {
-            Message::Text(bytes) => {
-                match serde_json::from_str::<WebSocketMessage>(bytes.as_str()) {
-                    Ok(websocket_msg) => match websocket_msg {
-                        WebSocketMessage::Register(nickname) => {
-                            state.users_list.insert(nickname.clone(), tx.clone());
-                            current_username = nickname.clone();
-
-                            // get the already connected users
-                            let current_users = state
-                                .users_list
-                                .iter()
-                                .map(|r| r.key().clone())
-                                .filter(|n| &current_username != n)
-                                .collect();
-                            tx.send(WebSocketMessage::RegisterSuccess(current_users))
-                                .await
-                                .ok();
-
-                            // notify every1
-                            state
-                                .broadcast_tx
-                                .send(WebSocketMessage::UserJoined(nickname))
-                                .ok();
+async fn read(
+    mut receiver: SplitStream<WebSocket>,
+    tx: Sender<WebSocketMessage>,
+    state: AppState,
+    nonce: String,
+) {
+    let connected_at = Instant::now();
+    // set once the client has signed our challenge
+    let mut endpoint_id: Option<String> = None;
+    // set once the client has joined a room
+    let mut current: Option<Session> = None;
+    let mut presence_task: Option<JoinHandle<()>> = None;
+    // whether the client is gone for good, as opposed to a dropped connection it may resume
+    let mut gone = false;
+    let mut shutting_down = state.shutting_down.subscribe();
+    // in case the server started shutting down while this socket was being upgraded
+    shutting_down.mark_changed();
+    let mut warned = false;
+    // answering pings keeps a socket alive, but not one that never says who it is
+    let auth_deadline = tokio::time::sleep(state.timeouts.auth_timeout);
+    tokio::pin!(auth_deadline);
+
+    loop {
+        // any frame counts as a sign of life, including the pongs answering our pings
+        let next = tokio::select! {
+            next = timeout(state.timeouts.idle_timeout, receiver.next()) => next,
+            _ = &mut auth_deadline, if endpoint_id.is_none() => {
+                info!("evicting connection that never authenticated");
+                gone = true;
+                break;
+            }
+            _ = shutting_down.changed(), if !warned => {
+                if *shutting_down.borrow_and_update() {
+                    warned = true;
+                    let retry_after = state.timeouts.retry_after.as_secs();
+                    tx.send(WebSocketMessage::ServerShuttingDown { retry_after })
+                        .await
+                        .ok();
+                }
+                continue;
+            }
+        };
+        let msg = match next {
+            Ok(Some(Ok(msg))) => msg,
+            Ok(_) => break,
+            Err(_) => {
+                info!("evicting idle connection");
+                gone = true;
+                break;
+            }
+        };
+
+        let bytes = match msg {
+            Message::Text(bytes) => bytes,
+            Message::Close(_) => {
+                gone = true;
+                break;
+            }
+            _ => continue,
+        };
+
+        let websocket_msg = match serde_json::from_str::<WebSocketMessage>(bytes.as_str()) {
+            Ok(websocket_msg) => websocket_msg,
+            Err(e) => {
+                debug!("couldn't parse message: {e}");
+                state.metrics.json_errors.fetch_add(1, Ordering::Relaxed);
+                tx.send(WebSocketMessage::ErrorDeserializingJson(e.to_string()))
+                    .await
+                    .ok();
+                continue;
+            }
+        };
+        state.metrics.message("in", websocket_msg.kind());
+
+        let Some(endpoint) = &endpoint_id else {
+            let WebSocketMessage::Authenticate {
+                endpoint_id: claimed,
+                signature,
+            } = websocket_msg
+            else {
+                // nothing counts until we know who we're talking to
+                continue;
+            };
+            let payload = identity::challenge_payload(&nonce);
+            if let Err(e) = identity::verify(&claimed, &payload, &signature) {
+                warn!("rejecting client that failed the challenge: {e}");
+                let reason = format!("Couldn't verify your endpoint key: {e}");
+                tx.send(WebSocketMessage::RegisterRejected { reason })
+                    .await
+                    .ok();
+                gone = true;
+                break;
+            }
+            Span::current().record("endpoint", identity::fingerprint(&claimed));
+            debug!("authenticated");
+            endpoint_id = Some(claimed);
+            continue;
+        };
+
+        match websocket_msg {
+            WebSocketMessage::Register(nickname) => {
+                join(
+                    &state,
+                    DEFAULT_ROOM.to_string(),
+                    nickname,
+                    endpoint,
+                    &tx,
+                    &mut current,
+                    &mut presence_task,
+                )
+                .await;
+            }
+            WebSocketMessage::JoinRoom { room, nickname } => {
+                let room = room_name(&room);
+                join(
+                    &state,
+                    room.to_string(),
+                    nickname,
+                    endpoint,
+                    &tx,
+                    &mut current,
+                    &mut presence_task,
+                )
+                .await;
+            }
+            WebSocketMessage::Resume { token } => {
+                if let Some(task) = presence_task.take() {
+                    task.abort();
+                }
+                if let Some(session) = current.take() {
+                    state.leave_room(&session, &tx);
+                    state.expire_offers(&session);
+                }
+
+                match state.resume(&token, endpoint, tx.clone()) {
+                    Some((session, current_users, broadcast_rx)) => {
+                        Span::current().record("nickname", session.nickname.as_str());
+                        info!(room = %session.room, "resumed session");
+                        let resume_token = session.token.clone();
+                        current = Some(session);
+                        tx.send(WebSocketMessage::RegisterSuccess {
+                            users: current_users,
+                            resume_token,
+                        })
+                        .await
+                        .ok();
+                        presence_task = Some(tokio::spawn(
+                            broadcast_read(broadcast_rx, tx.clone(), state.metrics.clone())
+                                .in_current_span(),
+                        ));
+                    }
+                    None => {
+                        debug!("session to resume is gone");
+                        tx.send(WebSocketMessage::ResumeFailed).await.ok();
+                    }
+                }
+            }
+            WebSocketMessage::SendFile {
+                recipient,
+                nonce,
+                signed_at,
+                ticket,
+                files,
+                size,
+                signature,
+            } => {
+                let Some(Session { room, nickname, .. }) = &current else {
+                    continue;
+                };
+                let Some(recipient_tx) = state.find_user(room, &recipient) else {
+                    info!(%recipient, "dropping offer, the recipient isn't in the room");
+                    state.metrics.offers_dropped.fetch_add(1, Ordering::Relaxed);
+                    continue;
+                };
+                state
+                    .metrics
+                    .offers_forwarded
+                    .fetch_add(1, Ordering::Relaxed);
+                let id = state.next_offer_id.fetch_add(1, Ordering::Relaxed);
+                info!(offer = id, %recipient, size, "forwarding offer");
+                state.offers.insert(
+                    id,
+                    PendingOffer {
+                        room: room.clone(),
+                        sender: nickname.clone(),
+                        recipient: recipient.clone(),
+                        nonce,
+                        accepted: false,
+                    },
+                );
+
+                let offer = Offer {
+                    id,
+                    sender: nickname.clone(),
+                    sent_at: unix_millis(),
+                    ticket,
+                    files,
+                    size,
+                    sender_id: endpoint.clone(),
+                    room: room.clone(),
+                    recipient,
+                    nonce,
+                    signed_at,
+                    signature,
+                };
+                recipient_tx
+                    .send(WebSocketMessage::IncomingOffer(offer))
+                    .await
+                    .ok();
+            }
+            WebSocketMessage::RespondOffer { offer_id, accepted } => {
+                let Some(Session {
+                    room,
+                    nickname,
+                    endpoint_id: recipient_id,
+                    ..
+                }) = &current
+                else {
+                    continue;
+                };
+                // only the recipient of an offer gets to answer it, and only once
+                let Some((_, mut offer)) = state.offers.remove_if(&offer_id, |_, o| {
+                    &o.room == room && &o.recipient == nickname && !o.accepted
+                }) else {
+                    continue;
+                };
+                info!(offer = offer_id, accepted, "offer answered");
+                let sender_tx = state.find_user(room, &offer.sender);
+                let recipient = offer.recipient.clone();
+                let nonce = offer.nonce;
+                if accepted {
+                    // kept until the recipient confirms the download
+                    offer.accepted = true;
+                    state.offers.insert(offer_id, offer);
+                }
+                if let Some(sender_tx) = sender_tx {
+                    let response = if accepted {
+                        // the endpoint the recipient proved it holds the key of, never one
+                        // it names itself
+                        WebSocketMessage::OfferAccepted {
+                            offer_id,
+                            nonce,
+                            recipient,
+                            endpoint_id: recipient_id.clone(),
                         }
-                        WebSocketMessage::SendFile { recipient, ticket } => {
-                            if let Some(maybe) = state.users_list.get(&recipient) {
-                                let recipient_rx = maybe.value().clone();
-                                recipient_rx
-                                    .send(WebSocketMessage::ReceiveFile(ticket))
-                                    .await
-                                    .ok();
-                            }
+                    } else {
+                        WebSocketMessage::OfferDeclined {
+                            offer_id,
+                            nonce,
+                            recipient,
                         }
-                        _ => {}
-                    },
-                    Err(e) => {
-                        tx.send(WebSocketMessage::ErrorDeserializingJson(e.to_string()))
-                            .await
-                            .ok();
-                    }
+                    };
+                    sender_tx.send(response).await.ok();
+                }
+            }
+            WebSocketMessage::TransferComplete { offer_id } => {
+                let Some(Session { room, nickname, .. }) = &current else {
+                    continue;
+                };
+                let Some((_, offer)) = state.offers.remove_if(&offer_id, |_, o| {
+                    &o.room == room && &o.recipient == nickname && o.accepted
+                }) else {
+                    continue;
+                };
+                info!(offer = offer_id, "offer delivered");
+                if let Some(sender_tx) = state.find_user(room, &offer.sender) {
+                    sender_tx
+                        .send(WebSocketMessage::Delivered {
+                            offer_id,
+                            nonce: offer.nonce,
+                            recipient: offer.recipient,
+                        })
+                        .await
+                        .ok();
                 }
             }
             _ => {}
         }
     }
 
-    // remove user and notify the others
-    state.users_list.remove(&current_username);
-    state
-        .broadcast_tx
-        .send(WebSocketMessage::UserLeft(current_username))
-        .ok();
+    let lasted = connected_at.elapsed();
+    info!(secs = lasted.as_secs(), gone, "disconnected");
+    state.metrics.connection_closed(lasted);
+    if let Some(task) = presence_task {
+        task.abort();
+    }
+    let Some(session) = current else {
+        return;
+    };
+
+    if gone {
+        // remove user and notify the others
+        state.leave_room(&session, &tx);
+    }
+    // keep the slot around for a bit so a quick reconnect doesn't show up as a leave + join,
+    // and its offers so a restarted client can still finish them
+    tokio::spawn(
+        async move {
+            tokio::time::sleep(state.timeouts.resume_grace).await;
+            if !gone {
+                state.leave_room(&session, &tx);
+            }
+            state.expire_offers(&session);
+        }
+        .in_current_span(),
+    );
+}
+
+/// Moves the connection into `room`, leaving whatever room it was in before.
+async fn join(
+    state: &AppState,
+    room: String,
+    nickname: String,
+    endpoint_id: &str,
+    tx: &Sender<WebSocketMessage>,
+    current: &mut Option<Session>,
+    presence_task: &mut Option<JoinHandle<()>>,
+) {
+    if let Some(task) = presence_task.take() {
+        task.abort();
+    }
+    if let Some(session) = current.take() {
+        // moving on to another room or name, what was offered under the old one is void
+        state.leave_room(&session, tx);
+        state.expire_offers(&session);
+    }
+
+    let nickname = nickname.trim().to_string();
+    if nickname.is_empty() || nickname.len() > MAX_NICKNAME_LEN {
+        let reason = format!("Nickname must be between 1 and {MAX_NICKNAME_LEN} characters");
+        info!("rejected nickname: {reason}");
+        tx.send(WebSocketMessage::RegisterRejected { reason })
+            .await
+            .ok();
+        return;
+    }
+
+    let (session, current_users, broadcast_rx) =
+        match state.join_room(&room, &nickname, endpoint_id, tx.clone()) {
+            Ok(joined) => joined,
+            Err(reason) => {
+                info!("rejected nickname: {reason}");
+                tx.send(WebSocketMessage::RegisterRejected { reason })
+                    .await
+                    .ok();
+                return;
+            }
+        };
+    Span::current().record("nickname", session.nickname.as_str());
+    info!(%room, "joined");
+    let resume_token = session.token.clone();
+    *current = Some(session);
+
+    // roster goes out before any presence update so the client never sees them reordered
+    tx.send(WebSocketMessage::RegisterSuccess {
+        users: current_users,
+        resume_token,
+    })
+    .await
+    .ok();
+    *presence_task = Some(tokio::spawn(
+        broadcast_read(broadcast_rx, tx.clone(), state.metrics.clone()).in_current_span(),
+    ));
+}
+
+fn unix_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or_default()
 }
@@ -0,0 +1,166 @@
+use std::{
+    fmt::Write as _,
+    sync::atomic::{AtomicI64, AtomicU64, Ordering},
+    time::Duration,
+};
+
+use axum::{extract::State, http::header, response::IntoResponse};
+use dashmap::DashMap;
+
+use crate::state::AppState;
+
+/// Upper bounds of the connection duration buckets, in seconds.
+const DURATION_BUCKETS: [f64; 9] = [
+    1.0, 10.0, 60.0, 300.0, 900.0, 1800.0, 3600.0, 14400.0, 86400.0,
+];
+
+/// Counters for the `/metrics` endpoint, in the Prometheus text format.
+#[derive(Default)]
+pub struct Metrics {
+    pub connected_sockets: AtomicI64,
+    /// (direction, type) -> messages, `in` for what clients sent us and `out` for what we sent
+    messages: DashMap<(&'static str, &'static str), u64>,
+    pub offers_forwarded: AtomicU64,
+    /// offers whose recipient wasn't in the room
+    pub offers_dropped: AtomicU64,
+    pub json_errors: AtomicU64,
+    /// times a presence subscriber fell behind and missed broadcasts
+    pub broadcast_lagged: AtomicU64,
+    connection_duration: Histogram,
+}
+
+impl Metrics {
+    pub fn message(&self, direction: &'static str, kind: &'static str) {
+        *self.messages.entry((direction, kind)).or_default() += 1;
+    }
+
+    pub fn connection_closed(&self, lasted: Duration) {
+        self.connected_sockets.fetch_sub(1, Ordering::Relaxed);
+        self.connection_duration.observe(lasted);
+    }
+
+    fn render(&self, registered_users: usize) -> String {
+        let mut out = String::new();
+        let mut gauge = |name: &str, help: &str, value: String| {
+            writeln!(
+                out,
+                "# HELP {name} {help}\n# TYPE {name} gauge\n{name} {value}"
+            )
+            .unwrap();
+        };
+        gauge(
+            "fling_connected_sockets",
+            "Websockets currently open.",
+            self.connected_sockets.load(Ordering::Relaxed).to_string(),
+        );
+        gauge(
+            "fling_registered_users",
+            "Users currently in a room.",
+            registered_users.to_string(),
+        );
+
+        writeln!(
+            out,
+            "# HELP fling_messages_total Websocket messages by direction and type.\n# TYPE fling_messages_total counter"
+        )
+        .unwrap();
+        let mut messages = self
+            .messages
+            .iter()
+            .map(|m| (*m.key(), *m.value()))
+            .collect::<Vec<_>>();
+        messages.sort();
+        for ((direction, kind), count) in messages {
+            writeln!(
+                out,
+                "fling_messages_total{{direction=\"{direction}\",type=\"{kind}\"}} {count}"
+            )
+            .unwrap();
+        }
+
+        writeln!(
+            out,
+            "# HELP fling_offers_total Offers by whether they reached their recipient.\n# TYPE fling_offers_total counter"
+        )
+        .unwrap();
+        for (outcome, counter) in [
+            ("forwarded", &self.offers_forwarded),
+            ("dropped", &self.offers_dropped),
+        ] {
+            writeln!(
+                out,
+                "fling_offers_total{{outcome=\"{outcome}\"}} {}",
+                counter.load(Ordering::Relaxed)
+            )
+            .unwrap();
+        }
+
+        let mut counter = |name: &str, help: &str, value: &AtomicU64| {
+            writeln!(
+                out,
+                "# HELP {name} {help}\n# TYPE {name} counter\n{name} {}",
+                value.load(Ordering::Relaxed)
+            )
+            .unwrap();
+        };
+        counter(
+            "fling_json_errors_total",
+            "Messages that weren't valid json.",
+            &self.json_errors,
+        );
+        counter(
+            "fling_broadcast_lagged_total",
+            "Times a client fell behind its room's presence updates.",
+            &self.broadcast_lagged,
+        );
+
+        self.connection_duration.render(
+            &mut out,
+            "fling_connection_duration_seconds",
+            "How long websockets stayed open.",
+        );
+        out
+    }
+}
+
+#[derive(Default)]
+struct Histogram {
+    /// non-cumulative, one per bucket plus one for everything above the last
+    buckets: [AtomicU64; DURATION_BUCKETS.len() + 1],
+    sum_micros: AtomicU64,
+}
+
+impl Histogram {
+    fn observe(&self, value: Duration) {
+        let secs = value.as_secs_f64();
+        let bucket = DURATION_BUCKETS
+            .iter()
+            .position(|&le| secs <= le)
+            .unwrap_or(DURATION_BUCKETS.len());
+        self.buckets[bucket].fetch_add(1, Ordering::Relaxed);
+        self.sum_micros
+            .fetch_add(value.as_micros() as u64, Ordering::Relaxed);
+    }
+
+    fn render(&self, out: &mut String, name: &str, help: &str) {
+        writeln!(out, "# HELP {name} {help}\n# TYPE {name} histogram").unwrap();
+        let mut count = 0;
+        for (i, bucket) in self.buckets.iter().enumerate() {
+            count += bucket.load(Ordering::Relaxed);
+            let le = DURATION_BUCKETS
+                .get(i)
+                .map_or("+Inf".to_string(), |le| le.to_string());
+            writeln!(out, "{name}_bucket{{le=\"{le}\"}} {count}").unwrap();
+        }
+        let sum = self.sum_micros.load(Ordering::Relaxed) as f64 / 1e6;
+        writeln!(out, "{name}_sum {sum}\n{name}_count {count}").unwrap();
+    }
+}
+
+pub async fn serve(State(state): State<AppState>) -> impl IntoResponse {
+    let registered_users = state.rooms.iter().map(|r| r.users.len()).sum();
+    (
+        [(header::CONTENT_TYPE, "text/plain; version=0.0.4")],
+        state.metrics.render(registered_users),
+    )
+}
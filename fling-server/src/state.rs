@@ -0,0 +1,354 @@
+use std::{
+    collections::HashMap,
+    env,
+    sync::{atomic::AtomicU64, Arc},
+    time::Duration,
+};
+
+use dashmap::DashMap;
+use shared::websocket_messages::WebSocketMessage;
+use tokio::sync::{broadcast, mpsc::Sender, watch};
+
+use crate::metrics::Metrics;
+
+pub const MAX_NICKNAME_LEN: usize = 32;
+
+pub struct Room {
+    pub users: HashMap<String, Sender<WebSocketMessage>>,
+    pub broadcast_tx: broadcast::Sender<WebSocketMessage>,
+}
+
+impl Room {
+    fn new() -> Self {
+        let (broadcast_tx, _) = broadcast::channel::<WebSocketMessage>(100);
+        Self {
+            users: HashMap::new(),
+            broadcast_tx,
+        }
+    }
+}
+
+/// An offer relayed to its recipient, kept until it's declined, the recipient confirms it
+/// downloaded everything, or either of them has been gone for longer than the resume grace.
+pub struct PendingOffer {
+    pub room: String,
+    pub sender: String,
+    pub recipient: String,
+    /// the sender's nonce for it, echoed back with every answer
+    pub nonce: u64,
+    /// whether the recipient said yes, after which only its [`WebSocketMessage::TransferComplete`]
+    /// is expected
+    pub accepted: bool,
+}
+
+/// A registered user, identified across reconnects by its resume token.
+#[derive(Debug, Clone)]
+pub struct Session {
+    pub room: String,
+    pub nickname: String,
+    pub token: String,
+    /// iroh endpoint the client proved it holds the key of
+    pub endpoint_id: String,
+}
+
+/// Keepalive, reconnect and shutdown timings, all configurable through env vars.
+#[derive(Clone, Copy)]
+pub struct Timeouts {
+    /// How often we ping clients.
+    pub ping_interval: Duration,
+    /// How long a socket may stay silent before it's evicted.
+    pub idle_timeout: Duration,
+    /// How long a new socket gets to answer our challenge before it's dropped.
+    pub auth_timeout: Duration,
+    /// How long a dropped user keeps its slot while waiting for it to resume.
+    pub resume_grace: Duration,
+    /// How long open sockets get to close on their own once we're shutting down.
+    pub shutdown_deadline: Duration,
+    /// How long clients are told to wait before reconnecting when we shut down.
+    pub retry_after: Duration,
+}
+
+impl Timeouts {
+    pub fn from_env() -> Self {
+        let secs = |key: &str, default: u64| {
+            env::var(key)
+                .ok()
+                .and_then(|v| v.parse::<u64>().ok())
+                .unwrap_or(default)
+        };
+        Self {
+            ping_interval: Duration::from_secs(secs("PING_INTERVAL_SECS", 20)),
+            idle_timeout: Duration::from_secs(secs("IDLE_TIMEOUT_SECS", 60)),
+            auth_timeout: Duration::from_secs(secs("AUTH_TIMEOUT_SECS", 10)),
+            resume_grace: Duration::from_secs(secs("RESUME_GRACE_SECS", 30)),
+            shutdown_deadline: Duration::from_secs(secs("SHUTDOWN_DEADLINE_SECS", 10)),
+            retry_after: Duration::from_secs(secs("SHUTDOWN_RETRY_AFTER_SECS", 5)),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct AppState {
+    pub timeouts: Timeouts,
+    pub rooms: Arc<DashMap<String, Room>>,
+    pub offers: Arc<DashMap<u64, PendingOffer>>,
+    pub next_offer_id: Arc<AtomicU64>,
+    pub next_connection_id: Arc<AtomicU64>,
+    /// resume token -> session
+    pub sessions: Arc<DashMap<String, Session>>,
+    pub metrics: Arc<Metrics>,
+    /// flips to `true` once, when the server starts shutting down
+    pub shutting_down: Arc<watch::Sender<bool>>,
+}
+
+impl AppState {
+    pub fn new(timeouts: Timeouts) -> Self {
+        Self {
+            timeouts,
+            rooms: Arc::new(DashMap::new()),
+            offers: Arc::new(DashMap::new()),
+            next_offer_id: Arc::new(AtomicU64::new(1)),
+            next_connection_id: Arc::new(AtomicU64::new(1)),
+            sessions: Arc::new(DashMap::new()),
+            metrics: Arc::new(Metrics::default()),
+            shutting_down: Arc::new(watch::Sender::new(false)),
+        }
+    }
+
+    /// Adds the user to `room` (creating it if needed) and returns its new session, the users
+    /// that were already there plus a subscription to the room's presence broadcasts.
+    ///
+    /// Fails with a human readable reason if the nickname is already taken in that room.
+    pub fn join_room(
+        &self,
+        room: &str,
+        nickname: &str,
+        endpoint_id: &str,
+        tx: Sender<WebSocketMessage>,
+    ) -> Result<(Session, Vec<String>, broadcast::Receiver<WebSocketMessage>), String> {
+        // the entry guard keeps the shard locked so a concurrent leave can't drop the room
+        // between creating it and inserting the user, and two clients can't claim the same name
+        let mut r = self.rooms.entry(room.to_string()).or_insert_with(Room::new);
+        if r.users.contains_key(nickname) {
+            return Err(format!("\"{nickname}\" is already taken in this room"));
+        }
+
+        let current_users = r
+            .users
+            .keys()
+            .filter(|n| n.as_str() != nickname)
+            .cloned()
+            .collect();
+        r.users.insert(nickname.to_string(), tx);
+
+        let broadcast_rx = r.broadcast_tx.subscribe();
+        r.broadcast_tx
+            .send(WebSocketMessage::UserJoined(nickname.to_string()))
+            .ok();
+        drop(r);
+
+        let session = Session {
+            room: room.to_string(),
+            nickname: nickname.to_string(),
+            token: uuid::Uuid::new_v4().to_string(),
+            endpoint_id: endpoint_id.to_string(),
+        };
+        self.sessions.insert(session.token.clone(), session.clone());
+
+        Ok((session, current_users, broadcast_rx))
+    }
+
+    /// Hands a still existing session over to a new connection, without telling the room.
+    ///
+    /// Only the endpoint that started the session can take it over, a leaked token isn't enough.
+    pub fn resume(
+        &self,
+        token: &str,
+        endpoint_id: &str,
+        tx: Sender<WebSocketMessage>,
+    ) -> Option<(Session, Vec<String>, broadcast::Receiver<WebSocketMessage>)> {
+        let session = self.sessions.get(token)?.value().clone();
+        if session.endpoint_id != endpoint_id {
+            return None;
+        }
+
+        let mut r = self.rooms.get_mut(&session.room)?;
+        let slot = r.users.get_mut(&session.nickname)?;
+        *slot = tx;
+
+        let current_users = r
+            .users
+            .keys()
+            .filter(|n| **n != session.nickname)
+            .cloned()
+            .collect();
+        let broadcast_rx = r.broadcast_tx.subscribe();
+
+        Some((session, current_users, broadcast_rx))
+    }
+
+    /// Removes the user from its room, notifies the others and drops the room once it's empty.
+    ///
+    /// Does nothing if the slot has been taken over by another connection (see [`Self::resume`]),
+    /// so a stale socket can't kick out the one that replaced it.
+    pub fn leave_room(&self, session: &Session, tx: &Sender<WebSocketMessage>) {
+        let Session {
+            room,
+            nickname,
+            token,
+            ..
+        } = session;
+
+        let removed = match self.rooms.get_mut(room) {
+            Some(mut r) if r.users.get(nickname).is_some_and(|t| t.same_channel(tx)) => {
+                r.users.remove(nickname);
+                r.broadcast_tx
+                    .send(WebSocketMessage::UserLeft(nickname.to_string()))
+                    .ok();
+                true
+            }
+            _ => false,
+        };
+        if !removed {
+            return;
+        }
+
+        self.rooms.remove_if(room, |_, r| r.users.is_empty());
+        self.sessions.remove(token);
+    }
+
+    /// Drops the offers `session` sent or received, unless its nickname is back in the room by
+    /// now. Called once its resume window is over, so a recipient that restarts and rejoins in
+    /// time can still confirm a download it finished.
+    pub fn expire_offers(&self, session: &Session) {
+        let Session { room, nickname, .. } = session;
+        if self.find_user(room, nickname).is_some() {
+            return;
+        }
+
+        // nobody is left to answer or receive the answer to these
+        self.offers
+            .retain(|_, o| &o.room != room || (&o.sender != nickname && &o.recipient != nickname));
+    }
+
+    pub fn find_user(&self, room: &str, nickname: &str) -> Option<Sender<WebSocketMessage>> {
+        self.rooms
+            .get(room)
+            .and_then(|r| r.users.get(nickname).cloned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::sync::mpsc;
+
+    use super::*;
+
+    fn state() -> AppState {
+        AppState::new(Timeouts {
+            ping_interval: Duration::from_secs(20),
+            idle_timeout: Duration::from_secs(60),
+            auth_timeout: Duration::from_secs(10),
+            resume_grace: Duration::from_secs(30),
+            shutdown_deadline: Duration::from_secs(10),
+            retry_after: Duration::from_secs(5),
+        })
+    }
+
+    fn channel() -> Sender<WebSocketMessage> {
+        mpsc::channel(8).0
+    }
+
+    fn offer(sender: &str, recipient: &str) -> PendingOffer {
+        PendingOffer {
+            room: "room".to_string(),
+            sender: sender.to_string(),
+            recipient: recipient.to_string(),
+            nonce: 1,
+            accepted: false,
+        }
+    }
+
+    #[test]
+    fn join_lists_who_is_there() {
+        let state = state();
+        state.join_room("room", "alice", "a", channel()).unwrap();
+        let (session, users, _) = state.join_room("room", "bob", "b", channel()).unwrap();
+        assert_eq!(users, vec!["alice".to_string()]);
+        assert_eq!(session.endpoint_id, "b");
+        assert!(state.sessions.contains_key(&session.token));
+    }
+
+    #[test]
+    fn duplicate_nickname_is_rejected() {
+        let state = state();
+        state.join_room("room", "alice", "a", channel()).unwrap();
+        assert!(state.join_room("room", "alice", "b", channel()).is_err());
+        // names are only unique within a room
+        assert!(state.join_room("other", "alice", "b", channel()).is_ok());
+    }
+
+    #[test]
+    fn resume_needs_the_same_endpoint() {
+        let state = state();
+        let (session, ..) = state.join_room("room", "alice", "a", channel()).unwrap();
+        assert!(state.resume(&session.token, "b", channel()).is_none());
+        assert!(state.resume("unknown", "a", channel()).is_none());
+
+        let tx = channel();
+        let (resumed, ..) = state.resume(&session.token, "a", tx.clone()).unwrap();
+        assert_eq!(resumed.nickname, "alice");
+        assert!(state.find_user("room", "alice").unwrap().same_channel(&tx));
+    }
+
+    #[test]
+    fn stale_socket_cannot_leave_for_the_new_one() {
+        let state = state();
+        let old = channel();
+        let (session, ..) = state.join_room("room", "alice", "a", old.clone()).unwrap();
+        let new = channel();
+        state.resume(&session.token, "a", new.clone()).unwrap();
+
+        state.leave_room(&session, &old);
+        assert!(state.find_user("room", "alice").is_some());
+        assert!(state.sessions.contains_key(&session.token));
+
+        state.leave_room(&session, &new);
+        assert!(state.find_user("room", "alice").is_none());
+        assert!(!state.sessions.contains_key(&session.token));
+        // the room went with its last user
+        assert!(!state.rooms.contains_key("room"));
+    }
+
+    #[test]
+    fn offers_outlive_a_leave_until_they_expire() {
+        let state = state();
+        let tx = channel();
+        let (session, ..) = state.join_room("room", "alice", "a", tx.clone()).unwrap();
+        state.join_room("room", "bob", "b", channel()).unwrap();
+        state.offers.insert(1, offer("alice", "bob"));
+        state.offers.insert(2, offer("bob", "alice"));
+        state.offers.insert(3, offer("bob", "carol"));
+
+        state.leave_room(&session, &tx);
+        assert_eq!(state.offers.len(), 3);
+
+        state.expire_offers(&session);
+        assert_eq!(state.offers.len(), 1);
+        assert!(state.offers.contains_key(&3));
+    }
+
+    #[test]
+    fn offers_are_kept_when_the_user_is_back() {
+        let state = state();
+        let tx = channel();
+        let (session, ..) = state.join_room("room", "alice", "a", tx.clone()).unwrap();
+        state.offers.insert(1, offer("bob", "alice"));
+
+        state.leave_room(&session, &tx);
+        // restarted and joined again under the same name
+        state.join_room("room", "alice", "a", channel()).unwrap();
+        state.expire_offers(&session);
+        assert!(state.offers.contains_key(&1));
+    }
+}
@@ -0,0 +1,89 @@
+use axum::{
+    http::{header, HeaderMap, StatusCode, Uri},
+    response::{IntoResponse, Response},
+};
+
+/// A file of the browser client, embedded at build time.
+pub struct Asset {
+    /// path relative to the site root, without the leading slash
+    pub path: &'static str,
+    pub etag: &'static str,
+    pub body: &'static [u8],
+}
+
+include!(concat!(env!("OUT_DIR"), "/web_assets.rs"));
+
+/// Serves the browser client for anything that isn't the websocket.
+pub async fn serve(uri: Uri, headers: HeaderMap) -> Response {
+    let path = match uri.path().trim_start_matches('/') {
+        "" => "index.html",
+        path => path,
+    };
+    let Some(asset) = ASSETS.iter().find(|a| a.path == path) else {
+        return StatusCode::NOT_FOUND.into_response();
+    };
+
+    let cache_control = cache_control(asset.path);
+    let not_modified = headers
+        .get(header::IF_NONE_MATCH)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|tags| tags.split(',').any(|tag| tag.trim() == asset.etag));
+    if not_modified {
+        return (
+            StatusCode::NOT_MODIFIED,
+            [
+                (header::ETAG, asset.etag),
+                (header::CACHE_CONTROL, cache_control),
+            ],
+        )
+            .into_response();
+    }
+
+    (
+        [
+            (header::CONTENT_TYPE, content_type(asset.path)),
+            (header::ETAG, asset.etag),
+            (header::CACHE_CONTROL, cache_control),
+        ],
+        asset.body,
+    )
+        .into_response()
+}
+
+/// Whether the client was bundled at all, the server works without it.
+pub fn is_bundled() -> bool {
+    !ASSETS.is_empty()
+}
+
+fn content_type(path: &str) -> &'static str {
+    match path.rsplit_once('.').map(|(_, ext)| ext) {
+        Some("html") => "text/html; charset=utf-8",
+        Some("js") => "text/javascript; charset=utf-8",
+        Some("wasm") => "application/wasm",
+        Some("css") => "text/css; charset=utf-8",
+        Some("json") => "application/json",
+        Some("webmanifest") => "application/manifest+json",
+        Some("svg") => "image/svg+xml",
+        Some("png") => "image/png",
+        Some("ico") => "image/x-icon",
+        _ => "application/octet-stream",
+    }
+}
+
+/// Files Trunk names after their hash never change, everything else has to be revalidated so a
+/// new deploy is picked up right away.
+fn cache_control(path: &str) -> &'static str {
+    let stem = path.rsplit('/').next().unwrap_or(path);
+    let stem = stem.split_once('.').map_or(stem, |(stem, _)| stem);
+    // the wasm module is named after the js glue, `<name>-<hash>_bg.wasm`
+    let stem = stem.trim_end_matches("_bg");
+    let hashed = stem
+        .rsplit_once('-')
+        .is_some_and(|(_, hash)| hash.len() == 16 && hash.chars().all(|c| c.is_ascii_hexdigit()));
+
+    if hashed {
+        "public, max-age=31536000, immutable"
+    } else {
+        "no-cache"
+    }
+}
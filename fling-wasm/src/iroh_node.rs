@@ -1,46 +1,128 @@
+use std::collections::HashMap;
+
 use anyhow::Result;
 use bytes::Bytes;
 use eframe::wasm_bindgen::JsCast;
 use futures::{channel::mpsc::UnboundedSender, stream, SinkExt, StreamExt};
-use iroh::{protocol::Router, Endpoint};
+use iroh::{protocol::Router, Endpoint, EndpointId, SecretKey};
 use iroh_blobs::{
     api::{blobs::AddProgressItem, TempTag},
     format::collection::Collection,
+    provider::events::{
+        AbortReason, ConnectMode, EventMask, EventSender, ProviderMessage, RequestMode,
+    },
     store::mem::MemStore,
-    BlobsProtocol,
+    BlobsProtocol, Hash,
+};
+use shared::{
+    transfer::TransferId,
+    upload::{self, Audience, Upload},
+    websocket_messages::OFFER_TTL,
 };
 use wasm_bindgen_futures::{spawn_local, JsFuture};
 use web_sys::{
     console,
-    js_sys::{Reflect, Uint8Array},
+    js_sys::{Date, Reflect, Uint8Array},
     Blob, ReadableStreamDefaultReader,
 };
 
+/// Expiries are `Date::now()` milliseconds.
+type Uploads = upload::Uploads<Hash, EndpointId, f64>;
+
 pub struct IrohNode {
     pub endpoint: Endpoint,
     pub blobs_protocol: BlobsProtocol,
     store: MemStore,
+    uploads: Uploads,
     _router: Router,
 }
 
 impl IrohNode {
-    pub async fn new() -> Result<Self> {
-        let endpoint = Endpoint::bind().await?;
+    /// Only collections made by [`Self::import`] are served, and only to whoever
+    /// [`Self::allow`] lets fetch them, same as on native.
+    pub async fn new(secret_key: SecretKey) -> Result<Self> {
+        let endpoint = Endpoint::builder().secret_key(secret_key).bind().await?;
         let store = MemStore::default();
-        let blobs_protocol = BlobsProtocol::new(&store, None);
+        let (events, provider_events) = EventSender::channel(
+            32,
+            EventMask {
+                connected: ConnectMode::Notify,
+                get: RequestMode::Intercept,
+                get_many: RequestMode::Disabled,
+                push: RequestMode::Disabled,
+                ..EventMask::DEFAULT
+            },
+        );
+        let blobs_protocol = BlobsProtocol::new(&store, Some(events));
         let router = Router::builder(endpoint.clone())
             .accept(iroh_blobs::ALPN, blobs_protocol.clone())
             .spawn();
 
+        let uploads = Uploads::default();
+        let uploads_clone = uploads.clone();
+        spawn_local(async move {
+            let mut provider_events = provider_events;
+            // connection id -> the peer on the other end
+            let mut peers = HashMap::new();
+            while let Some(event) = provider_events.recv().await {
+                match event {
+                    ProviderMessage::ClientConnectedNotify(msg) => {
+                        if let Some(endpoint_id) = msg.endpoint_id {
+                            peers.insert(msg.connection_id, endpoint_id);
+                        }
+                    }
+                    ProviderMessage::ConnectionClosed(msg) => {
+                        peers.remove(&msg.connection_id);
+                    }
+                    ProviderMessage::GetRequestReceived(msg) => {
+                        let peer = peers.get(&msg.connection_id).copied();
+                        let admitted = uploads_clone
+                            .lock()
+                            .unwrap()
+                            .get_mut(&msg.request.hash)
+                            .is_some_and(|upload| upload.admit(peer, Date::now()));
+                        let result = if admitted {
+                            Ok(())
+                        } else {
+                            console::warn_1(&"refused a fetch nobody was granted".into());
+                            Err(AbortReason::Permission)
+                        };
+                        msg.tx.send(result).await.ok();
+                    }
+                    _ => {}
+                }
+            }
+        });
+
         Ok(Self {
             endpoint,
             store,
             blobs_protocol,
+            uploads,
             _router: router,
         })
     }
 
-    pub async fn import(&self, blobs: Vec<(Blob, String)>) -> Result<TempTag> {
+    /// Lets `peer` fetch what transfer `id` imported.
+    pub fn allow(&self, id: TransferId, peer: EndpointId) {
+        let mut uploads = self.uploads.lock().unwrap();
+        for upload in uploads.values_mut().filter(|upload| upload.id == id) {
+            upload.audience = Audience::Peer(peer);
+        }
+    }
+
+    /// Refuses any further request for what transfer `id` imported, returns whether there was
+    /// anything to revoke.
+    pub fn revoke(&self, id: TransferId) -> bool {
+        let mut uploads = self.uploads.lock().unwrap();
+        let before = uploads.len();
+        uploads.retain(|_, upload| upload.id != id);
+        uploads.len() != before
+    }
+
+    /// Imports `blobs` into a single collection that nobody may fetch until [`Self::allow`]ed.
+    pub async fn import(&self, id: TransferId, blobs: Vec<(Blob, String)>) -> Result<TempTag> {
+        let size = blobs.iter().map(|(blob, _)| blob.size() as u64).sum();
         let infos = futures::stream::iter(blobs)
             .map(|(blob, name)| {
                 let store = self.store.clone();
@@ -77,6 +159,9 @@ impl IrohNode {
             .collect::<Collection>();
 
         let tt = collection.store(&self.store).await?;
+        let mut upload = Upload::new(id, size);
+        upload.expires = Some(Date::now() + OFFER_TTL.as_millis() as f64);
+        self.uploads.lock().unwrap().insert(tt.hash(), upload);
         Ok(tt)
     }
 }
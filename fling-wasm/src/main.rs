@@ -1,45 +1,63 @@
+use std::{path::Path, str::FromStr};
+
 use anyhow::{anyhow, Context, Result};
 use futures::channel::mpsc::{self, UnboundedSender};
+use futures::{
+    future::{abortable, AbortHandle},
+    stream::{SplitSink, SplitStream},
+};
 use futures_util::{SinkExt, StreamExt};
 use gloo_net::websocket::{futures::WebSocket, Message};
+use iroh::{EndpointId, SecretKey};
 use iroh_blobs::ticket::BlobTicket;
 use shared::{
-    app_events::AppEvent, network::Network, ui_events::UIEvent,
-    websocket_messages::WebSocketMessage,
+    app_events::AppEvent,
+    history::HistoryEntry,
+    identity,
+    network::Network,
+    settings::{Settings, DEFAULT_SERVER_URL},
+    transfer::TransferState,
+    ui_events::UIEvent,
+    websocket_messages::{room_name, WebSocketMessage, DEFAULT_ROOM},
 };
 use ui::UI;
 use wasm_bindgen_futures::spawn_local;
-use web_sys::{
-    console::{self},
-    Blob, Window,
-};
+use web_sys::{js_sys, Blob, Window};
 
 use crate::iroh_node::IrohNode;
 
 mod iroh_node;
 
-const WS_URL: &str = "wss://fling-server.fly.dev/ws";
+/// localStorage keys the device identity is kept under.
+const NICKNAME_KEY: &str = "fling_nickname";
+const SECRET_KEY_KEY: &str = "fling_secret_key";
+const HISTORY_KEY: &str = "fling_history";
 
 struct WasmNetwork {
     to_ws: mpsc::UnboundedSender<UIEvent>,
     tx: mpsc::UnboundedSender<AppEvent>,
     rx: mpsc::UnboundedReceiver<AppEvent>,
+    endpoint_id: String,
 }
 
 impl WasmNetwork {
-    fn new(nickname: &str) -> Self {
+    fn new(server_url: String, secret_key: [u8; 32]) -> Self {
         let (to_ws, mut from_ui) = mpsc::unbounded::<UIEvent>();
         let (tx, rx) = mpsc::unbounded::<AppEvent>();
 
+        tx.unbounded_send(AppEvent::History(load_history())).ok();
+
         let mut tx_clone = tx.clone();
         spawn_local(async move {
+            let mut server_url = server_url;
+            let ws_url = server_url.clone();
             let ws_init = async move {
-                let ws = WebSocket::open(WS_URL).context("can't connect to ws")?;
+                let ws = WebSocket::open(&ws_url).context("can't connect to ws")?;
                 let (write, read) = ws.split();
                 Ok::<_, anyhow::Error>((write, read))
             };
             let iroh_init = async move {
-                let iroh_node = IrohNode::new().await?;
+                let iroh_node = IrohNode::new(SecretKey::from_bytes(&secret_key)).await?;
                 Ok(iroh_node)
             };
 
@@ -47,27 +65,50 @@ impl WasmNetwork {
             let result = futures::try_join!(ws_init, iroh_init);
             match result {
                 Ok(((mut write, mut read), iroh_node)) => {
-                    spawn_local(async move {
-                        while let Some(msg) = read.next().await {
-                            match msg {
-                                Ok(m) => process_message(m, tx_clone_1.clone()).await,
-                                Err(e) => {}
-                            }
-                        }
-                    });
+                    if let Err(e) = authenticate(&mut write, &mut read, &secret_key).await {
+                        tx_clone.send(AppEvent::FatalError(e)).await.ok();
+                        return;
+                    }
+                    let mut reader = spawn_reader(read, tx_clone_1.clone());
 
+                    let mut tx_clone_2 = tx_clone.clone();
                     spawn_local(async move {
+                        // the one we last asked to join, offers are signed for it
+                        let mut room = DEFAULT_ROOM.to_string();
                         while let Some(msg) = from_ui.next().await {
                             match msg {
-                                UIEvent::PrepareFile { recipient, files } => {
+                                UIEvent::PrepareFile { id, recipient, files } => {
+                                    tx_clone_2
+                                        .send(AppEvent::TransferUpdate(id, TransferState::Importing))
+                                        .await
+                                        .ok();
                                     let blobs = files
                                         .into_iter()
                                         .map(|f| {
                                             (Blob::from(f.inner().to_owned()), f.inner().name())
                                         })
                                         .collect::<Vec<_>>();
-
-                                    let tt = iroh_node.import(blobs).await.unwrap();
+                                    let names = blobs
+                                        .iter()
+                                        .map(|(_, name)| name.clone())
+                                        .collect::<Vec<_>>();
+                                    let size = blobs.iter().map(|(blob, _)| blob.size() as u64).sum();
+
+                                    let tt = match iroh_node.import(id, blobs).await {
+                                        Ok(tt) => tt,
+                                        Err(e) => {
+                                            let error = format!("Failed to import file(s): {e:#}");
+                                            tx_clone_2
+                                                .send(AppEvent::TransferUpdate(id, TransferState::Failed(error)))
+                                                .await
+                                                .ok();
+                                            continue;
+                                        }
+                                    };
+                                    tx_clone_2
+                                        .send(AppEvent::TransferDetails(id, tt.hash().to_string(), Vec::new()))
+                                        .await
+                                        .ok();
 
                                     let ticket = BlobTicket::new(
                                         iroh_node.endpoint.addr(),
@@ -75,17 +116,116 @@ impl WasmNetwork {
                                         iroh_blobs::BlobFormat::HashSeq,
                                     )
                                     .to_string();
-
-                                    let json =
-                                        WebSocketMessage::SendFile { recipient, ticket }.to_json();
-
-                                    write.send(Message::Text(json.into())).await.unwrap();
-                                    console::log_1(&"sent".into());
+                                    let nonce = random_nonce();
+                                    let signed_at = js_sys::Date::now() as u64;
+                                    let signature = identity::sign(
+                                        &secret_key,
+                                        &identity::offer_payload(
+                                            &room, &recipient, nonce, signed_at, &ticket, &names, size,
+                                        ),
+                                    );
+
+                                    let json = WebSocketMessage::SendFile {
+                                        recipient,
+                                        nonce,
+                                        signed_at,
+                                        ticket,
+                                        files: names,
+                                        size,
+                                        signature,
+                                    }
+                                    .to_json();
+
+                                    tx_clone_2.send(AppEvent::Offered(id, nonce)).await.ok();
+                                    if let Err(e) = write.send(Message::Text(json.into())).await {
+                                        // nobody is going to accept it, stop serving it
+                                        iroh_node.revoke(id);
+                                        let error = format!("Websocket send failed: {e}");
+                                        tx_clone_2
+                                            .send(AppEvent::TransferUpdate(id, TransferState::Failed(error)))
+                                            .await
+                                            .ok();
+                                        continue;
+                                    }
+                                    tx_clone_2
+                                        .send(AppEvent::TransferUpdate(id, TransferState::Offering))
+                                        .await
+                                        .ok();
+                                }
+                                UIEvent::UpdateSettings(settings) => {
+                                    save_nickname(&settings.nickname);
+                                    if settings.server_url == server_url {
+                                        continue;
+                                    }
+                                    let connected = async {
+                                        let ws = WebSocket::open(&settings.server_url)
+                                            .context("can't connect to ws")?;
+                                        let (mut new_write, mut new_read) = ws.split();
+                                        authenticate(&mut new_write, &mut new_read, &secret_key)
+                                            .await?;
+                                        Ok::<_, anyhow::Error>((new_write, new_read))
+                                    };
+                                    match connected.await {
+                                        Ok((new_write, new_read)) => {
+                                            // dropping both halves of the old socket closes it
+                                            reader.abort();
+                                            write = new_write;
+                                            reader = spawn_reader(new_read, tx_clone_1.clone());
+                                            server_url = settings.server_url;
+                                            tx_clone_2.send(AppEvent::ReadyToPublishUser).await.ok();
+                                        }
+                                        Err(e) => {
+                                            tx_clone_2
+                                                .send(AppEvent::FatalError(e))
+                                                .await
+                                                .ok();
+                                        }
+                                    }
+                                }
+                                // imports here finish in one go, only what a send offered
+                                // can still be taken back
+                                UIEvent::CancelTransfer(id) => {
+                                    if iroh_node.revoke(id) {
+                                        tx_clone_2
+                                            .send(AppEvent::TransferUpdate(id, TransferState::Cancelled))
+                                            .await
+                                            .ok();
+                                    }
+                                }
+                                UIEvent::GrantAccess { id, endpoint_id } => {
+                                    match EndpointId::from_str(&endpoint_id) {
+                                        Ok(endpoint_id) => iroh_node.allow(id, endpoint_id),
+                                        Err(e) => {
+                                            let error = format!("Recipient sent a bad endpoint id: {e}");
+                                            tx_clone_2
+                                                .send(AppEvent::TransferUpdate(id, TransferState::Failed(error)))
+                                                .await
+                                                .ok();
+                                        }
+                                    }
+                                }
+                                UIEvent::RecordHistory(mut entry) => {
+                                    entry.finished_at = (js_sys::Date::now() / 1000.0) as u64;
+                                    save_history_entry(&entry);
+                                    tx_clone_2.send(AppEvent::History(vec![entry])).await.ok();
+                                }
+                                UIEvent::DownloadFile { id, .. } => {
+                                    let error = "Receiving files isn't supported in the browser yet";
+                                    tx_clone_2
+                                        .send(AppEvent::TransferUpdate(id, TransferState::Failed(error.into())))
+                                        .await
+                                        .ok();
                                 }
-                                _ => {
-                                    let json = msg.to_ws().expect("shouldn't happen");
-                                    let json = json.to_json();
-                                    write.send(Message::Text(json)).await.ok();
+                                // nothing gets downloaded here, so nothing is left pending
+                                UIEvent::DiscardDownload(_) => {}
+                                msg @ (UIEvent::JoinRoom { .. } | UIEvent::RespondOffer { .. }) => {
+                                    if let UIEvent::JoinRoom { room: joined, .. } = &msg {
+                                        room = room_name(joined).to_string();
+                                    }
+                                    let Some(ws_msg) = msg.to_ws() else {
+                                        continue;
+                                    };
+                                    write.send(Message::Text(ws_msg.to_json())).await.ok();
                                 }
                             }
                         }
@@ -97,7 +237,12 @@ impl WasmNetwork {
             }
         });
 
-        Self { to_ws, tx, rx }
+        Self {
+            to_ws,
+            tx,
+            rx,
+            endpoint_id: identity::endpoint_id(&secret_key),
+        }
     }
 }
 
@@ -127,6 +272,23 @@ impl Network for WasmNetwork {
             }
         });
     }
+
+    fn open_folder_dialog(&mut self) {
+        // browsers only hand out files through rfd, the UI doesn't offer folders here
+    }
+
+    fn show_in_folder(&self, _path: &Path) {
+        // sent files stay wherever the user picked them from, the browser keeps no paths
+    }
+
+    fn check_space(&self, _size: u64) -> Result<()> {
+        // received files go through the browser's download prompt, which checks for itself
+        Ok(())
+    }
+
+    fn endpoint_id(&self) -> String {
+        self.endpoint_id.clone()
+    }
 }
 
 #[cfg(target_arch = "wasm32")]
@@ -149,8 +311,12 @@ fn main() {
             .dyn_into::<web_sys::HtmlCanvasElement>()
             .expect("canvas id isn't an HtmlCanvasElement");
 
-        let nickname = get_nickname(&window);
-        let wasm_network = WasmNetwork::new(&nickname);
+        let settings = Settings {
+            nickname: load_nickname(&window),
+            server_url: get_server_url(&window),
+            ..Default::default()
+        };
+        let wasm_network = WasmNetwork::new(settings.server_url.clone(), load_secret_key(&window));
 
         let start_result = eframe::WebRunner::new()
             .start(
@@ -159,8 +325,8 @@ fn main() {
                 Box::new(|cc| {
                     Ok(Box::new(UI::new(
                         cc,
-                        nickname,
                         PathBuf::new(),
+                        settings,
                         wasm_network,
                     )))
                 }),
@@ -178,6 +344,151 @@ fn main() {
     });
 }
 
+/// Reads the server from a `?server=` query parameter, falling back to the origin serving the page.
+fn get_server_url(window: &Window) -> String {
+    let location = window.location();
+
+    let from_query = location
+        .search()
+        .ok()
+        .and_then(|search| web_sys::UrlSearchParams::new_with_str(&search).ok())
+        .and_then(|params| params.get("server"))
+        .filter(|server| !server.is_empty());
+    if let Some(server) = from_query {
+        return server;
+    }
+
+    match (location.protocol(), location.host()) {
+        (Ok(protocol), Ok(host)) if !host.is_empty() => {
+            let scheme = if protocol == "https:" { "wss" } else { "ws" };
+            format!("{scheme}://{host}/ws")
+        }
+        _ => DEFAULT_SERVER_URL.to_string(),
+    }
+}
+
+/// Answers the challenge the server opens every connection with, proving which endpoint we are.
+async fn authenticate(
+    write: &mut SplitSink<WebSocket, Message>,
+    read: &mut SplitStream<WebSocket>,
+    secret_key: &[u8; 32],
+) -> Result<()> {
+    while let Some(msg) = read.next().await {
+        let Message::Text(s) = msg.map_err(|e| anyhow!(e.to_string()))? else {
+            continue;
+        };
+        let Ok(WebSocketMessage::Challenge { nonce }) = serde_json::from_str(&s) else {
+            continue;
+        };
+
+        let json = WebSocketMessage::Authenticate {
+            endpoint_id: identity::endpoint_id(secret_key),
+            signature: identity::sign(secret_key, &identity::challenge_payload(&nonce)),
+        }
+        .to_json();
+        write
+            .send(Message::Text(json))
+            .await
+            .map_err(|e| anyhow!(e.to_string()))?;
+        return Ok(());
+    }
+    Err(anyhow!("server closed the connection before the challenge"))
+}
+
+/// Forwards server messages to the UI until the returned handle is aborted.
+fn spawn_reader(
+    mut read: SplitStream<WebSocket>,
+    tx: UnboundedSender<AppEvent>,
+) -> AbortHandle {
+    // the browser answers the server's keepalive pings on its own
+    let (reader, handle) = abortable(async move {
+        while let Some(msg) = read.next().await {
+            match msg {
+                Ok(m) => process_message(m, tx.clone()).await,
+                Err(e) => {}
+            }
+        }
+    });
+    spawn_local(async move {
+        reader.await.ok();
+    });
+    handle
+}
+
+/// The nickname picked on the first visit, or a new random one if the browser forgot it.
+fn load_nickname(window: &Window) -> String {
+    let storage = window.local_storage().ok().flatten();
+    if let Some(nickname) = storage
+        .as_ref()
+        .and_then(|storage| storage.get_item(NICKNAME_KEY).ok().flatten())
+        .filter(|nickname| !nickname.trim().is_empty())
+    {
+        return nickname;
+    }
+
+    let nickname = get_nickname(window);
+    save_nickname(&nickname);
+    nickname
+}
+
+fn save_nickname(nickname: &str) {
+    if let Some(storage) = local_storage() {
+        storage.set_item(NICKNAME_KEY, nickname).ok();
+    }
+}
+
+fn load_history() -> Vec<HistoryEntry> {
+    local_storage()
+        .and_then(|storage| storage.get_item(HISTORY_KEY).ok().flatten())
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+/// localStorage only holds strings, so the whole history gets written again each time.
+fn save_history_entry(entry: &HistoryEntry) {
+    let mut history = load_history();
+    history.push(entry.clone());
+    if let Some(storage) = local_storage()
+        && let Ok(json) = serde_json::to_string(&history)
+    {
+        storage.set_item(HISTORY_KEY, &json).ok();
+    }
+}
+
+fn local_storage() -> Option<web_sys::Storage> {
+    web_sys::window().and_then(|window| window.local_storage().ok().flatten())
+}
+
+/// The browser's iroh key, made on the first visit so the endpoint id stays the same after that.
+fn load_secret_key(window: &Window) -> [u8; 32] {
+    let storage = window.local_storage().ok().flatten();
+    if let Some(secret_key) = storage
+        .as_ref()
+        .and_then(|storage| storage.get_item(SECRET_KEY_KEY).ok().flatten())
+        .and_then(|hex| identity::decode_secret_key(&hex))
+    {
+        return secret_key;
+    }
+
+    let mut secret_key = [0u8; 32];
+    let crypto = window.crypto().expect("no crypto");
+    crypto.get_random_values_with_u8_array(&mut secret_key).unwrap();
+    if let Some(storage) = storage {
+        storage
+            .set_item(SECRET_KEY_KEY, &identity::encode_secret_key(&secret_key))
+            .ok();
+    }
+    secret_key
+}
+
+/// A fresh id for an offer we sign, see [`shared::websocket_messages::Offer::nonce`].
+fn random_nonce() -> u64 {
+    let mut bytes = [0u8; 8];
+    let crypto = web_sys::window().and_then(|window| window.crypto().ok()).expect("no crypto");
+    crypto.get_random_values_with_u8_array(&mut bytes).unwrap();
+    u64::from_le_bytes(bytes)
+}
+
 fn get_nickname(window: &Window) -> String {
     let mut arr = [0u8; 3];
     let crypto = window.crypto().expect("no crypto");
@@ -198,9 +509,37 @@ async fn process_message(message: Message, mut tx: UnboundedSender<AppEvent>) {
     if let Message::Text(s) = message {
         match serde_json::from_str::<WebSocketMessage>(&s) {
             Ok(msg) => match msg {
-                WebSocketMessage::RegisterSuccess(users) => {
+                WebSocketMessage::RegisterSuccess { users, .. } => {
                     tx.send(AppEvent::RegisterSuccess(users)).await.ok();
                 }
+                WebSocketMessage::RegisterRejected { reason } => {
+                    tx.send(AppEvent::RegisterRejected(reason)).await.ok();
+                }
+                WebSocketMessage::OfferAccepted {
+                    nonce,
+                    recipient,
+                    endpoint_id,
+                    ..
+                } => {
+                    tx.send(AppEvent::OfferAccepted(nonce, recipient, endpoint_id))
+                        .await
+                        .ok();
+                }
+                WebSocketMessage::OfferDeclined {
+                    nonce, recipient, ..
+                } => {
+                    tx.send(AppEvent::OfferDeclined(nonce, recipient)).await.ok();
+                }
+                WebSocketMessage::Delivered {
+                    nonce, recipient, ..
+                } => {
+                    tx.send(AppEvent::Delivered(nonce, recipient)).await.ok();
+                }
+                WebSocketMessage::ServerShuttingDown { retry_after } => {
+                    tx.send(AppEvent::ServerShuttingDown(retry_after))
+                        .await
+                        .ok();
+                }
                 _ => {}
             },
             Err(e) => {}
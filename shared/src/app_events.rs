@@ -1,16 +1,50 @@
+use std::path::PathBuf;
+
+use crate::{
+    history::HistoryEntry,
+    transfer::{TransferId, TransferState},
+    websocket_messages::Offer,
+};
+
 pub enum AppEvent {
     ReadyToPublishUser,
     RegisterSuccess(Vec<String>),
+    RegisterRejected(String),
     AddNewUser(String),
     RemoveUser(String),
+    /// The websocket dropped, carries the number of failed attempts so far.
+    Reconnecting(u32),
+    /// The server is restarting, we'll be back in about that many seconds.
+    ServerShuttingDown(u64),
 
     ReceivedFile(Vec<rfd::FileHandle>),
-    UpdateProgressValue(f32),
-    ImportStart,
-    ImportDone,
-    DownloadStart,
-    DownloadFile(String),
-    DownloadDone,
+    /// A transfer moved on to its next state.
+    TransferUpdate(TransferId, TransferState),
+    /// Bytes done out of the total, while a transfer is importing or downloading.
+    TransferProgress(TransferId, u64, u64),
+    /// Hash of the collection a transfer travels in and the files on disk it's made of: the
+    /// picked ones for a send, once imported, or where a receive saved them.
+    TransferDetails(TransferId, String, Vec<PathBuf>),
+    /// Bytes a peer has fetched out of the total, while it downloads something we offered. The
+    /// peer is its short endpoint id.
+    UploadProgress(TransferId, String, u64, u64),
+    /// A send's offer is going out, signed with that nonce. The recipient's answers carry the
+    /// same one, it's how they're matched to the send.
+    Offered(TransferId, u64),
+    IncomingOffer(Offer),
+    /// The recipient accepted the offer with that nonce, with the endpoint it's going to fetch
+    /// from.
+    OfferAccepted(u64, String, String),
+    OfferDeclined(u64, String),
+    /// The recipient has every file of what we sent it.
+    Delivered(u64, String),
+    /// Received entries that weren't written, each as `name: reason`.
+    EntriesRejected(Vec<String>),
+    /// Downloads that were started but never finished, they survive restarts.
+    PendingDownloads(Vec<Offer>),
+    /// Entries to add to the history: everything recorded before at startup, then each one as
+    /// it gets saved.
+    History(Vec<HistoryEntry>),
 
     FatalError(anyhow::Error),
 }
@@ -1,8 +1,10 @@
 #[derive(Debug)]
 pub enum AppState {
     Connecting,
+    Reconnecting { attempt: u32 },
     Ready,
 
     PublishUser,
     WaitForRegisterConfirmation,
+    NicknameRejected { reason: String, input: String },
 }
@@ -0,0 +1,41 @@
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::transfer::{Direction, TransferState};
+
+/// A finished transfer, kept so there's a record of it once its toast is gone.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    pub direction: Direction,
+    /// who it went to or came from
+    pub peer: String,
+    pub files: Vec<String>,
+    pub size: u64,
+    /// Hash of the collection the files travelled in, empty if it never got that far.
+    #[serde(default)]
+    pub hash: String,
+    /// Unix time in seconds, filled in by the network layer when it saves the entry.
+    #[serde(default)]
+    pub finished_at: u64,
+    /// Seconds from leaving the queue to finishing.
+    pub duration: f64,
+    /// One of the finished states.
+    pub outcome: TransferState,
+    /// The files that were picked for a send, or where a receive saved them.
+    #[serde(default)]
+    pub paths: Vec<PathBuf>,
+}
+
+impl HistoryEntry {
+    /// Whether `query` appears in the peer or one of the file names, ignoring case.
+    pub fn matches(&self, query: &str) -> bool {
+        let query = query.trim().to_lowercase();
+        query.is_empty()
+            || self.peer.to_lowercase().contains(&query)
+            || self
+                .files
+                .iter()
+                .any(|name| name.to_lowercase().contains(&query))
+    }
+}
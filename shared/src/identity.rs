@@ -0,0 +1,137 @@
+//! Signatures made with a client's iroh endpoint key, so clients can check what the server
+//! relays instead of trusting it.
+//!
+//! Keys and signatures travel hex encoded, the way iroh prints endpoint ids.
+
+use data_encoding::HEXLOWER;
+use ed25519_dalek::{Signature, Signer, SigningKey, VerifyingKey};
+
+/// What a client signs to prove it holds the key of the endpoint it connects as.
+pub fn challenge_payload(nonce: &str) -> Vec<u8> {
+    format!("fling challenge {nonce}").into_bytes()
+}
+
+/// What a sender signs for an offer, so the recipient knows the ticket really is from it.
+///
+/// Covering the room, recipient and the sender's `nonce` for the offer keeps a signed offer from
+/// being relayed to someone else or passed off as a new one, and `signed_at` from being replayed
+/// once recipients have forgotten about it.
+pub fn offer_payload(
+    room: &str,
+    recipient: &str,
+    nonce: u64,
+    signed_at: u64,
+    ticket: &str,
+    files: &[String],
+    size: u64,
+) -> Vec<u8> {
+    serde_json::to_vec(&(
+        "fling offer",
+        room,
+        recipient,
+        nonce,
+        signed_at,
+        ticket,
+        files,
+        size,
+    ))
+        .expect("error serializing json BUG!")
+}
+
+/// The endpoint id belonging to `secret_key`.
+pub fn endpoint_id(secret_key: &[u8; 32]) -> String {
+    let key = SigningKey::from_bytes(secret_key).verifying_key();
+    HEXLOWER.encode(key.as_bytes())
+}
+
+/// The first few characters of `endpoint_id`, enough to tell devices apart at a glance. Same
+/// length as iroh's short form.
+pub fn fingerprint(endpoint_id: &str) -> &str {
+    endpoint_id.get(..10).unwrap_or(endpoint_id)
+}
+
+/// How a secret key is kept between launches.
+pub fn encode_secret_key(secret_key: &[u8; 32]) -> String {
+    HEXLOWER.encode(secret_key)
+}
+
+pub fn decode_secret_key(hex: &str) -> Option<[u8; 32]> {
+    decode(hex.trim())
+}
+
+pub fn sign(secret_key: &[u8; 32], payload: &[u8]) -> String {
+    HEXLOWER.encode(&SigningKey::from_bytes(secret_key).sign(payload).to_bytes())
+}
+
+/// Checks that `signature` over `payload` was made with the key of `endpoint_id`.
+pub fn verify(endpoint_id: &str, payload: &[u8], signature: &str) -> Result<(), String> {
+    let key = decode::<32>(endpoint_id)
+        .and_then(|key| VerifyingKey::from_bytes(&key).ok())
+        .ok_or_else(|| format!("invalid endpoint id \"{endpoint_id}\""))?;
+    let signature = decode::<64>(signature).ok_or_else(|| "invalid signature".to_string())?;
+    key.verify_strict(payload, &Signature::from_bytes(&signature))
+        .map_err(|_| "signature doesn't match".to_string())
+}
+
+fn decode<const N: usize>(hex: &str) -> Option<[u8; N]> {
+    HEXLOWER
+        .decode(hex.as_bytes())
+        .ok()
+        .and_then(|bytes| bytes.try_into().ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY: [u8; 32] = [7; 32];
+    const OTHER_KEY: [u8; 32] = [9; 32];
+
+    fn offer(room: &str, recipient: &str, nonce: u64) -> Vec<u8> {
+        offer_payload(room, recipient, nonce, 1000, "ticket", &["a.txt".to_string()], 3)
+    }
+
+    #[test]
+    fn signature_round_trips() {
+        let payload = challenge_payload("nonce");
+        let signature = sign(&KEY, &payload);
+        assert_eq!(verify(&endpoint_id(&KEY), &payload, &signature), Ok(()));
+    }
+
+    #[test]
+    fn tampered_payload_fails() {
+        let signature = sign(&KEY, &offer("lobby", "bob", 1));
+        let id = endpoint_id(&KEY);
+        assert!(verify(&id, &offer("lobby", "bob", 1), &signature).is_ok());
+        assert!(verify(&id, &offer("lobby", "mallory", 1), &signature).is_err());
+        assert!(verify(&id, &offer("lobby", "bob", 2), &signature).is_err());
+        assert!(verify(&id, &offer("other", "bob", 1), &signature).is_err());
+        let later = offer_payload("lobby", "bob", 1, 2000, "ticket", &["a.txt".to_string()], 3);
+        assert!(verify(&id, &later, &signature).is_err());
+        assert!(verify(&id, &challenge_payload("nonce"), &signature).is_err());
+    }
+
+    #[test]
+    fn wrong_key_fails() {
+        let payload = challenge_payload("nonce");
+        let signature = sign(&KEY, &payload);
+        assert!(verify(&endpoint_id(&OTHER_KEY), &payload, &signature).is_err());
+        assert!(verify(&endpoint_id(&KEY), &payload, &sign(&OTHER_KEY, &payload)).is_err());
+    }
+
+    #[test]
+    fn malformed_input_fails() {
+        let payload = challenge_payload("nonce");
+        let signature = sign(&KEY, &payload);
+        assert!(verify("not hex", &payload, &signature).is_err());
+        assert!(verify(&endpoint_id(&KEY), &payload, "abcd").is_err());
+        assert!(verify(&endpoint_id(&KEY)[..10], &payload, &signature).is_err());
+    }
+
+    #[test]
+    fn secret_key_round_trips() {
+        let encoded = encode_secret_key(&KEY);
+        assert_eq!(decode_secret_key(&format!("{encoded}\n")), Some(KEY));
+        assert_eq!(decode_secret_key("zz"), None);
+    }
+}
@@ -1,5 +1,11 @@
 pub mod app_events;
 pub mod app_state;
+pub mod history;
+pub mod identity;
 pub mod network;
+pub mod settings;
+pub mod transfer;
 pub mod ui_events;
+pub mod units;
+pub mod upload;
 pub mod websocket_messages;
@@ -1,3 +1,5 @@
+use std::path::Path;
+
 use anyhow::Result;
 
 use crate::{app_events::AppEvent, ui_events::UIEvent};
@@ -8,4 +10,14 @@ pub trait Network {
     fn try_recv(&mut self) -> Option<AppEvent>;
 
     fn open_file_dialog(&mut self);
+    fn open_folder_dialog(&mut self);
+    /// Opens the folder holding `path` in the system's file manager.
+    fn show_in_folder(&self, path: &Path);
+
+    /// Makes sure a download of `size` bytes fits on disk, by the same rule the download itself
+    /// goes by, counting the copy kept until it's saved. Always fine where nothing is saved.
+    fn check_space(&self, size: u64) -> Result<()>;
+
+    /// Our iroh endpoint id, which stays the same between launches.
+    fn endpoint_id(&self) -> String;
 }
@@ -0,0 +1,69 @@
+use std::{fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+pub const DEFAULT_SERVER_URL: &str = "wss://fling-server.fly.dev/ws";
+
+/// User preferences that can be changed from the UI at runtime.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// Name others see us as, empty until one gets picked on first launch.
+    pub nickname: String,
+    /// Websocket url of the signaling server.
+    pub server_url: String,
+    /// What to do when a received file already exists in the download folder.
+    pub conflict_policy: ConflictPolicy,
+    /// How many imports and downloads run at once, the rest wait in line.
+    pub max_transfers: usize,
+    /// Biggest transfer that will be downloaded, in bytes. `None` means no limit.
+    pub max_download_size: Option<u64>,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            nickname: String::new(),
+            server_url: DEFAULT_SERVER_URL.to_string(),
+            conflict_policy: ConflictPolicy::default(),
+            max_transfers: 2,
+            max_download_size: None,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConflictPolicy {
+    /// Keep both, saving the new one as `file (1).txt`.
+    #[default]
+    Rename,
+    /// Keep the existing file and drop the received one.
+    Skip,
+    Overwrite,
+}
+
+impl ConflictPolicy {
+    pub const ALL: [ConflictPolicy; 3] = [Self::Rename, Self::Skip, Self::Overwrite];
+}
+
+impl fmt::Display for ConflictPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ConflictPolicy::Rename => "rename",
+            ConflictPolicy::Skip => "skip",
+            ConflictPolicy::Overwrite => "overwrite",
+        })
+    }
+}
+
+impl FromStr for ConflictPolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|policy| policy.to_string() == s)
+            .ok_or_else(|| format!("expected one of rename, skip, overwrite, got \"{s}\""))
+    }
+}
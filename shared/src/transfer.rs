@@ -0,0 +1,211 @@
+use std::{
+    fmt,
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+use serde::{Deserialize, Serialize};
+
+/// Identifies an import or download so it can be followed and cancelled. Only unique within one
+/// run of the app, it never leaves the machine.
+pub type TransferId = u64;
+
+static NEXT_TRANSFER_ID: AtomicU64 = AtomicU64::new(1);
+
+pub fn next_transfer_id() -> TransferId {
+    NEXT_TRANSFER_ID.fetch_add(1, Ordering::Relaxed)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Direction {
+    Send,
+    Receive,
+}
+
+/// Where a transfer is at. A send goes queued, importing, offering, uploading once accepted and
+/// delivered once the recipient confirms it has everything. A receive goes queued, downloading,
+/// exporting, done. Either can fail or be cancelled on the way.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "state", content = "reason", rename_all = "snake_case")]
+pub enum TransferState {
+    /// Waiting for one of the concurrent transfer slots.
+    Queued,
+    Importing,
+    /// Imported and offered, waiting for the recipient to answer.
+    Offering,
+    /// Accepted, the recipient is fetching it.
+    Uploading,
+    /// The recipient confirmed it got every file.
+    Delivered,
+    Downloading,
+    Exporting,
+    Done,
+    Failed(String),
+    Cancelled,
+}
+
+impl TransferState {
+    pub fn is_finished(&self) -> bool {
+        matches!(
+            self,
+            Self::Done | Self::Delivered | Self::Failed(_) | Self::Cancelled
+        )
+    }
+}
+
+impl fmt::Display for TransferState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Queued => "Queued",
+            Self::Importing => "Preparing",
+            Self::Offering => "Waiting for answer",
+            Self::Uploading => "Sending",
+            Self::Delivered => "Delivered",
+            Self::Downloading => "Downloading",
+            Self::Exporting => "Saving",
+            Self::Done => "Done",
+            Self::Failed(_) => "Failed",
+            Self::Cancelled => "Cancelled",
+        })
+    }
+}
+
+/// Speed and ETA of a transfer, worked out from its progress updates.
+///
+/// Time is passed in as seconds from any fixed point, so it works the same with the UI's frame
+/// clock and with `Instant` on the command line.
+#[derive(Debug, Clone, Default)]
+pub struct Throughput {
+    /// time and bytes of the first update
+    start: Option<(f64, u64)>,
+    /// time and bytes the current speed was last worked out at
+    sample: Option<(f64, u64)>,
+    latest: Option<(f64, u64)>,
+    current: f64,
+    smoothed: f64,
+}
+
+impl Throughput {
+    /// Shortest stretch the current speed is measured over, updates come in much faster than
+    /// that and would make it jump around.
+    const WINDOW: f64 = 0.5;
+    /// Weight of the newest sample in the smoothed speed.
+    const SMOOTHING: f64 = 0.2;
+
+    pub fn update(&mut self, done: u64, now: f64) {
+        self.latest = Some((now, done));
+        let Some((time, bytes)) = self.sample else {
+            self.start = Some((now, done));
+            self.sample = Some((now, done));
+            return;
+        };
+
+        let elapsed = now - time;
+        if elapsed < Self::WINDOW {
+            return;
+        }
+        self.current = done.saturating_sub(bytes) as f64 / elapsed;
+        self.smoothed = if self.smoothed == 0.0 {
+            self.current
+        } else {
+            Self::SMOOTHING * self.current + (1.0 - Self::SMOOTHING) * self.smoothed
+        };
+        self.sample = Some((now, done));
+    }
+
+    /// Bytes per second over the last half second or so.
+    pub fn current(&self) -> f64 {
+        self.current
+    }
+
+    /// Bytes per second, smoothed so it doesn't jump around with every sample.
+    pub fn smoothed(&self) -> f64 {
+        self.smoothed
+    }
+
+    /// Seconds between the first and the latest update.
+    pub fn elapsed(&self) -> f64 {
+        match (self.start, self.latest) {
+            (Some((start, _)), Some((latest, _))) => latest - start,
+            _ => 0.0,
+        }
+    }
+
+    /// Bytes per second from the first update to the latest, what a finished transfer averaged.
+    pub fn average(&self) -> f64 {
+        match (self.start, self.latest) {
+            (Some((start, from)), Some((latest, to))) if latest > start => {
+                to.saturating_sub(from) as f64 / (latest - start)
+            }
+            _ => 0.0,
+        }
+    }
+
+    /// Seconds left until `total` at the smoothed speed, `None` until there's a speed to go by.
+    pub fn eta(&self, total: u64) -> Option<f64> {
+        let (_, done) = self.latest?;
+        (self.smoothed > 0.0).then(|| total.saturating_sub(done) as f64 / self.smoothed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nothing_to_go_by_before_updates() {
+        let throughput = Throughput::default();
+        assert_eq!(throughput.current(), 0.0);
+        assert_eq!(throughput.average(), 0.0);
+        assert_eq!(throughput.elapsed(), 0.0);
+        assert_eq!(throughput.eta(100), None);
+    }
+
+    #[test]
+    fn zero_elapsed_has_no_speed() {
+        let mut throughput = Throughput::default();
+        throughput.update(100, 5.0);
+        throughput.update(200, 5.0);
+        assert_eq!(throughput.elapsed(), 0.0);
+        assert_eq!(throughput.average(), 0.0);
+        assert_eq!(throughput.current(), 0.0);
+        assert_eq!(throughput.eta(1000), None);
+    }
+
+    #[test]
+    fn zero_rate_has_no_eta() {
+        let mut throughput = Throughput::default();
+        throughput.update(0, 0.0);
+        throughput.update(0, 1.0);
+        assert_eq!(throughput.current(), 0.0);
+        assert_eq!(throughput.eta(1000), None);
+    }
+
+    #[test]
+    fn measures_over_the_window() {
+        let mut throughput = Throughput::default();
+        throughput.update(0, 0.0);
+        // too soon after the last sample to count
+        throughput.update(400, 0.2);
+        assert_eq!(throughput.current(), 0.0);
+
+        throughput.update(1000, 1.0);
+        assert_eq!(throughput.current(), 1000.0);
+        assert_eq!(throughput.smoothed(), 1000.0);
+        assert_eq!(throughput.eta(3000), Some(2.0));
+    }
+
+    #[test]
+    fn smooths_and_averages() {
+        let mut throughput = Throughput::default();
+        throughput.update(0, 0.0);
+        throughput.update(1000, 1.0);
+        throughput.update(3000, 2.0);
+        assert_eq!(throughput.current(), 2000.0);
+        assert_eq!(throughput.smoothed(), 1200.0);
+        assert_eq!(throughput.elapsed(), 2.0);
+        assert_eq!(throughput.average(), 1500.0);
+        // done is past the total, nothing left
+        assert_eq!(throughput.eta(2000), Some(0.0));
+    }
+}
@@ -1,19 +1,52 @@
-use crate::websocket_messages::WebSocketMessage;
+use crate::{
+    history::HistoryEntry,
+    settings::Settings,
+    transfer::TransferId,
+    websocket_messages::{Offer, WebSocketMessage},
+};
 
 #[derive(Debug, Clone)]
 pub enum UIEvent {
-    Register(String),
+    JoinRoom {
+        room: String,
+        nickname: String,
+    },
     PrepareFile {
+        id: TransferId,
         recipient: String,
         files: Vec<rfd::FileHandle>,
     },
-    DownloadFile(String),
+    /// Downloads an accepted offer, or resumes it if it's one of the pending downloads.
+    DownloadFile {
+        id: TransferId,
+        offer: Offer,
+    },
+    /// Stops an import or download, dropping anything it half wrote. For a send it also stops
+    /// anyone from fetching what it offered.
+    CancelTransfer(TransferId),
+    /// Lets the recipient's endpoint fetch what a send offered, nobody can before that.
+    GrantAccess {
+        id: TransferId,
+        endpoint_id: String,
+    },
+    /// Forgets about an unfinished download, identified by its ticket.
+    DiscardDownload(String),
+    RespondOffer {
+        offer_id: u64,
+        accepted: bool,
+    },
+    UpdateSettings(Settings),
+    /// Saves a finished transfer to the history.
+    RecordHistory(HistoryEntry),
 }
 
 impl UIEvent {
     pub fn to_ws(self) -> Option<WebSocketMessage> {
         match self {
-            Self::Register(nickname) => Some(WebSocketMessage::Register(nickname)),
+            Self::JoinRoom { room, nickname } => Some(WebSocketMessage::JoinRoom { room, nickname }),
+            Self::RespondOffer { offer_id, accepted } => {
+                Some(WebSocketMessage::RespondOffer { offer_id, accepted })
+            }
             _ => None,
         }
     }
@@ -0,0 +1,168 @@
+/// Human readable byte count, e.g. `1.5 MB`.
+pub fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["B", "KB", "MB", "GB", "TB"];
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{bytes} {}", UNITS[0])
+    } else {
+        format!("{size:.1} {}", UNITS[unit])
+    }
+}
+
+/// Parses a byte count like `500MB`, `1.5 GB` or `4096`, using the same 1024 based units as
+/// [`format_size`].
+pub fn parse_size(s: &str) -> Result<u64, String> {
+    let s = s.trim();
+    let split = s
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(s.len());
+    let (number, unit) = s.split_at(split);
+    let number = number
+        .parse::<f64>()
+        .map_err(|_| format!("expected a size like 500MB or 2GB, got \"{s}\""))?;
+    let exponent = match unit.trim().to_ascii_uppercase().as_str() {
+        "" | "B" => 0,
+        "K" | "KB" => 1,
+        "M" | "MB" => 2,
+        "G" | "GB" => 3,
+        "T" | "TB" => 4,
+        other => return Err(format!("unknown size unit \"{other}\"")),
+    };
+    Ok((number * 1024f64.powi(exponent)) as u64)
+}
+
+/// Parses a duration like `90s`, `10m`, `2h` or `1d` into seconds, a bare number is seconds.
+pub fn parse_duration(s: &str) -> Result<u64, String> {
+    let s = s.trim();
+    let split = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+    let (number, unit) = s.split_at(split);
+    let number = number
+        .parse::<u64>()
+        .map_err(|_| format!("expected a duration like 10m or 2h, got \"{s}\""))?;
+    let unit = match unit.trim().to_ascii_lowercase().as_str() {
+        "" | "s" => 1,
+        "m" => 60,
+        "h" => 60 * 60,
+        "d" => 24 * 60 * 60,
+        other => return Err(format!("unknown duration unit \"{other}\"")),
+    };
+    number
+        .checked_mul(unit)
+        .ok_or_else(|| format!("duration \"{s}\" is too long"))
+}
+
+/// Human readable transfer speed, e.g. `12.5 MB/s`.
+pub fn format_speed(bytes_per_second: f64) -> String {
+    format!("{}/s", format_size(bytes_per_second as u64))
+}
+
+/// Human readable duration rounded to the second, e.g. `1h 05m`, `3m 20s` or `45s`.
+pub fn format_duration(seconds: f64) -> String {
+    let seconds = seconds.round() as u64;
+    let (hours, minutes, seconds) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+    if hours > 0 {
+        format!("{hours}h {minutes:02}m")
+    } else if minutes > 0 {
+        format!("{minutes}m {seconds:02}s")
+    } else {
+        format!("{seconds}s")
+    }
+}
+
+/// A unix time in seconds as a UTC date and time, e.g. `2025-03-14 09:26 UTC`.
+pub fn format_timestamp(unix_seconds: u64) -> String {
+    let (days, seconds) = (unix_seconds / 86400, unix_seconds % 86400);
+    // civil date from days since 1970-01-01, see http://howardhinnant.github.io/date_algorithms.html
+    let z = days as i64 + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    format!(
+        "{year}-{month:02}-{day:02} {:02}:{:02} UTC",
+        seconds / 3600,
+        seconds / 60 % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_sizes() {
+        assert_eq!(parse_size("4096"), Ok(4096));
+        assert_eq!(parse_size("2kb"), Ok(2048));
+        assert_eq!(parse_size("500MB"), Ok(500 * 1024 * 1024));
+        assert_eq!(parse_size(" 1.5 GB "), Ok(3 * 512 * 1024 * 1024));
+    }
+
+    #[test]
+    fn rejects_bad_sizes() {
+        assert!(parse_size("").is_err());
+        assert!(parse_size("MB").is_err());
+        assert!(parse_size("5XB").is_err());
+        assert!(parse_size("1.2.3MB").is_err());
+    }
+
+    #[test]
+    fn parses_durations() {
+        assert_eq!(parse_duration("90"), Ok(90));
+        assert_eq!(parse_duration("90s"), Ok(90));
+        assert_eq!(parse_duration("10m"), Ok(600));
+        assert_eq!(parse_duration(" 2H "), Ok(7200));
+        assert_eq!(parse_duration("1d"), Ok(86400));
+    }
+
+    #[test]
+    fn rejects_bad_durations() {
+        assert!(parse_duration("").is_err());
+        assert!(parse_duration("m").is_err());
+        assert!(parse_duration("10w").is_err());
+        assert!(parse_duration("1.5h").is_err());
+    }
+
+    #[test]
+    fn rejects_overflowing_durations() {
+        assert!(parse_duration(&format!("{}", u64::MAX)).is_ok());
+        assert!(parse_duration(&format!("{}d", u64::MAX / 60)).is_err());
+        // too big to even parse
+        assert!(parse_duration("99999999999999999999s").is_err());
+    }
+
+    #[test]
+    fn formats_speeds() {
+        assert_eq!(format_speed(0.0), "0 B/s");
+        assert_eq!(format_speed(512.4), "512 B/s");
+        assert_eq!(format_speed(1536.0), "1.5 KB/s");
+        assert_eq!(format_speed(12.5 * 1024.0 * 1024.0), "12.5 MB/s");
+    }
+
+    #[test]
+    fn formats_durations() {
+        assert_eq!(format_duration(0.0), "0s");
+        assert_eq!(format_duration(45.4), "45s");
+        // rounds up into the next minute
+        assert_eq!(format_duration(59.6), "1m 00s");
+        assert_eq!(format_duration(200.0), "3m 20s");
+        assert_eq!(format_duration(3900.0), "1h 05m");
+    }
+
+    #[test]
+    fn formats_timestamps() {
+        assert_eq!(format_timestamp(0), "1970-01-01 00:00 UTC");
+        assert_eq!(format_timestamp(951_868_800), "2000-03-01 00:00 UTC");
+        assert_eq!(format_timestamp(1_709_251_140), "2024-02-29 23:59 UTC");
+        assert_eq!(format_timestamp(1_741_944_360), "2025-03-14 09:26 UTC");
+    }
+}
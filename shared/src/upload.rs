@@ -0,0 +1,97 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use crate::transfer::TransferId;
+
+/// Who may fetch a collection we imported. `P` tells peers apart, an iroh endpoint id on both
+/// clients.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Audience<P> {
+    /// Nobody yet, e.g. while the recipient hasn't accepted the offer.
+    Nobody,
+    Peer(P),
+    /// Whoever fetches it first, nobody else after that.
+    First,
+    /// Anyone holding the ticket.
+    Anyone,
+}
+
+/// A collection we imported, with the transfer it belongs to and who may fetch it.
+///
+/// `T` is whatever clock the client has, `Instant` on native and `Date::now()` in the browser.
+#[derive(Debug)]
+pub struct Upload<P, T> {
+    pub id: TransferId,
+    /// size of its files
+    pub size: u64,
+    pub audience: Audience<P>,
+    pub expires: Option<T>,
+}
+
+impl<P: Copy + PartialEq, T: PartialOrd> Upload<P, T> {
+    /// Nobody may fetch it until it's given an audience.
+    pub fn new(id: TransferId, size: u64) -> Self {
+        Self {
+            id,
+            size,
+            audience: Audience::Nobody,
+            expires: None,
+        }
+    }
+
+    /// Whether `peer` may fetch it at `now`. The first peer to fetch a single-use one claims it.
+    pub fn admit(&mut self, peer: Option<P>, now: T) -> bool {
+        if self.expires.as_ref().is_some_and(|at| *at <= now) {
+            return false;
+        }
+        match (self.audience, peer) {
+            (Audience::Anyone, _) => true,
+            (Audience::Peer(allowed), Some(peer)) => allowed == peer,
+            (Audience::First, Some(peer)) => {
+                self.audience = Audience::Peer(peer);
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Collection hash -> what we know about it.
+pub type Uploads<H, P, T> = Arc<Mutex<HashMap<H, Upload<P, T>>>>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nobody_until_allowed() {
+        let mut upload = Upload::<u8, u64>::new(1, 10);
+        assert!(!upload.admit(Some(1), 0));
+
+        upload.audience = Audience::Peer(1);
+        assert!(upload.admit(Some(1), 0));
+        assert!(!upload.admit(Some(2), 0));
+        assert!(!upload.admit(None, 0));
+    }
+
+    #[test]
+    fn first_peer_claims_it() {
+        let mut upload = Upload::<u8, u64>::new(1, 10);
+        upload.audience = Audience::First;
+        assert!(!upload.admit(None, 0));
+        assert!(upload.admit(Some(2), 0));
+        assert!(upload.admit(Some(2), 0));
+        assert!(!upload.admit(Some(3), 0));
+    }
+
+    #[test]
+    fn nobody_once_expired() {
+        let mut upload = Upload::<u8, u64>::new(1, 10);
+        upload.audience = Audience::Anyone;
+        upload.expires = Some(5);
+        assert!(upload.admit(None, 4));
+        assert!(!upload.admit(None, 5));
+    }
+}
@@ -1,23 +1,189 @@
+use std::time::Duration;
+
 use serde::{Deserialize, Serialize};
 
+use crate::identity;
+
+/// Room every client lands in when it registers without picking one.
+pub const DEFAULT_ROOM: &str = "lobby";
+
+/// How long an offer holds. The sender serves its files that long and recipients refuse offers
+/// signed longer ago than that.
+pub const OFFER_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// The room a client asking to join `room` ends up in.
+pub fn room_name(room: &str) -> &str {
+    let room = room.trim();
+    if room.is_empty() { DEFAULT_ROOM } else { room }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type", content = "payload", rename_all = "snake_case")]
 pub enum WebSocketMessage {
+    /// Sent by the server as soon as a client connects, see [`Self::Authenticate`].
+    Challenge {
+        nonce: String,
+    },
+    /// The client's answer to [`Self::Challenge`], the nonce signed with its endpoint key. The
+    /// server ignores everything else until it gets a valid one.
+    Authenticate {
+        endpoint_id: String,
+        signature: String,
+    },
     Register(String),
-    RegisterSuccess(Vec<String>),
+    JoinRoom {
+        room: String,
+        nickname: String,
+    },
+    RegisterSuccess {
+        users: Vec<String>,
+        /// Lets a client that lost its connection take its slot back with [`Self::Resume`].
+        resume_token: String,
+    },
+    RegisterRejected {
+        reason: String,
+    },
+    Resume {
+        token: String,
+    },
+    ResumeFailed,
     UserJoined(String),
     UserLeft(String),
 
     SendFile {
         recipient: String,
+        /// Picked at random by the sender for each offer, see [`Offer::nonce`].
+        nonce: u64,
+        /// See [`Offer::signed_at`].
+        signed_at: u64,
         ticket: String,
+        files: Vec<String>,
+        size: u64,
+        /// Made over [`crate::identity::offer_payload`] with the sender's endpoint key.
+        signature: String,
+    },
+    IncomingOffer(Offer),
+    /// Carries no endpoint id on purpose, see [`Self::OfferAccepted`].
+    RespondOffer {
+        offer_id: u64,
+        accepted: bool,
+    },
+    OfferAccepted {
+        offer_id: u64,
+        /// The sender's [`Offer::nonce`], so it can tell which of its offers this answers.
+        nonce: u64,
+        recipient: String,
+        /// The recipient's endpoint, the only one the sender lets fetch the files. Filled in by
+        /// the server from the recipient's authenticated session, so a client can't get access
+        /// granted to an endpoint it doesn't hold the key of.
+        endpoint_id: String,
+    },
+    OfferDeclined {
+        offer_id: u64,
+        /// See [`Self::OfferAccepted`].
+        nonce: u64,
+        recipient: String,
+    },
+    /// Sent by the recipient once it has downloaded an accepted offer.
+    TransferComplete {
+        offer_id: u64,
+    },
+    /// Relayed to the sender when the recipient sends [`Self::TransferComplete`].
+    Delivered {
+        offer_id: u64,
+        /// See [`Self::OfferAccepted`].
+        nonce: u64,
+        recipient: String,
     },
-    ReceiveFile(String),
     ErrorDeserializingJson(String),
+    /// Sent by the server when it's about to go away, clients should reconnect after
+    /// `retry_after` seconds.
+    ServerShuttingDown {
+        retry_after: u64,
+    },
+}
+
+/// Files someone wants to send us, waiting for us to accept or decline them.
+///
+/// `id`, `sender`, `sender_id`, `room`, `recipient` and `sent_at` are filled in by the server, so a
+/// client can't pretend an offer came from someone else. The server itself is kept honest by the
+/// signature.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Offer {
+    pub id: u64,
+    pub sender: String,
+    /// Unix timestamp in milliseconds of when the server relayed the offer.
+    pub sent_at: u64,
+    pub ticket: String,
+    pub files: Vec<String>,
+    pub size: u64,
+    /// Endpoint the sender proved it holds the key of when it connected.
+    #[serde(default)]
+    pub sender_id: String,
+    /// Room the offer was sent in.
+    #[serde(default)]
+    pub room: String,
+    /// Nickname the offer was sent to.
+    #[serde(default)]
+    pub recipient: String,
+    /// The sender's own id for the offer. Unlike `id`, which the server assigns after the offer
+    /// is signed, it's covered by the signature, so recipients can tell a replayed offer apart.
+    #[serde(default)]
+    pub nonce: u64,
+    /// Unix timestamp in milliseconds of when the sender signed the offer. Unlike `sent_at` it's
+    /// covered by the signature, so recipients can tell when to stop expecting a replay.
+    #[serde(default)]
+    pub signed_at: u64,
+    /// The sender's signature over the room, recipient, nonce, signing time, ticket, files and
+    /// size.
+    #[serde(default)]
+    pub signature: String,
+}
+
+impl Offer {
+    /// Whether the offer was signed by `sender_id`, which is only worth anything once the
+    /// ticket is checked to point at that same endpoint.
+    pub fn verify(&self) -> Result<(), String> {
+        let payload = identity::offer_payload(
+            &self.room,
+            &self.recipient,
+            self.nonce,
+            self.signed_at,
+            &self.ticket,
+            &self.files,
+            self.size,
+        );
+        identity::verify(&self.sender_id, &payload, &self.signature)
+    }
 }
 
 impl WebSocketMessage {
     pub fn to_json(&self) -> String {
         serde_json::to_string(&self).expect("error serializing json BUG!")
     }
+
+    /// The message's `type` on the wire.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::Challenge { .. } => "challenge",
+            Self::Authenticate { .. } => "authenticate",
+            Self::Register(_) => "register",
+            Self::JoinRoom { .. } => "join_room",
+            Self::RegisterSuccess { .. } => "register_success",
+            Self::RegisterRejected { .. } => "register_rejected",
+            Self::Resume { .. } => "resume",
+            Self::ResumeFailed => "resume_failed",
+            Self::UserJoined(_) => "user_joined",
+            Self::UserLeft(_) => "user_left",
+            Self::SendFile { .. } => "send_file",
+            Self::IncomingOffer(_) => "incoming_offer",
+            Self::RespondOffer { .. } => "respond_offer",
+            Self::OfferAccepted { .. } => "offer_accepted",
+            Self::OfferDeclined { .. } => "offer_declined",
+            Self::TransferComplete { .. } => "transfer_complete",
+            Self::Delivered { .. } => "delivered",
+            Self::ErrorDeserializingJson(_) => "error_deserializing_json",
+            Self::ServerShuttingDown { .. } => "server_shutting_down",
+        }
+    }
 }
@@ -0,0 +1,101 @@
+use egui::{Color32, RichText};
+#[cfg(not(target_arch = "wasm32"))]
+use rfd::FileHandle;
+use shared::{
+    transfer::{Direction, TransferState},
+    units::{format_duration, format_size, format_timestamp},
+};
+
+use crate::{Network, UI};
+
+impl<N: Network> UI<N> {
+    /// Lists finished transfers, newest first, with a search over peers and file names.
+    pub fn show_history_window(&mut self, ctx: &egui::Context) {
+        if !self.history_open {
+            return;
+        }
+
+        let text_dim = Color32::from_rgb(140, 140, 140);
+        let mut open = true;
+        // the browser only gets at files the user picks again
+        #[cfg(not(target_arch = "wasm32"))]
+        let mut resend = None;
+        egui::Window::new("History")
+            .open(&mut open)
+            .collapsible(false)
+            .default_size([360.0, 400.0])
+            .anchor(egui::Align2::CENTER_CENTER, (0.0, 0.0))
+            .show(ctx, |ui| {
+                ui.add(
+                    egui::TextEdit::singleline(&mut self.history_search)
+                        .desired_width(f32::INFINITY)
+                        .hint_text("Search by name or file"),
+                );
+                ui.add_space(4.0);
+
+                let mut entries = self
+                    .history
+                    .iter()
+                    .enumerate()
+                    .rev()
+                    .filter(|(_, entry)| entry.matches(&self.history_search))
+                    .peekable();
+                if entries.peek().is_none() {
+                    ui.label(RichText::new("Nothing here yet").color(text_dim).size(12.0));
+                }
+
+                egui::ScrollArea::vertical().show(ui, |ui| {
+                    for (index, entry) in entries {
+                        let label = match entry.direction {
+                            Direction::Send => format!("{} file(s) to {}", entry.files.len(), entry.peer),
+                            Direction::Receive => format!("{} file(s) from {}", entry.files.len(), entry.peer),
+                        };
+                        ui.horizontal(|ui| {
+                            ui.label(RichText::new(label).size(12.0));
+                            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                                #[cfg(not(target_arch = "wasm32"))]
+                                if entry.direction == Direction::Send && !entry.paths.is_empty() {
+                                    let online = self.users.contains(&entry.peer);
+                                    let button = ui
+                                        .add_enabled(online, egui::Button::new(RichText::new("Re-send").size(11.0)).small())
+                                        .on_disabled_hover_text(format!("{} isn't online", entry.peer));
+                                    if button.clicked() {
+                                        resend = Some(index);
+                                    }
+                                }
+                                if let Some(path) = entry.paths.first()
+                                    && ui.small_button(RichText::new("Open folder").size(11.0)).clicked()
+                                {
+                                    self.network.show_in_folder(path);
+                                }
+                                let outcome = ui.label(RichText::new(entry.outcome.to_string()).color(text_dim).size(11.0));
+                                if let TransferState::Failed(reason) = &entry.outcome {
+                                    outcome.on_hover_text(reason);
+                                }
+                            });
+                        });
+
+                        let mut details = vec![format_size(entry.size), format_duration(entry.duration)];
+                        if entry.finished_at > 0 {
+                            details.push(format_timestamp(entry.finished_at));
+                        }
+                        ui.label(RichText::new(details.join(", ")).color(text_dim).size(11.0))
+                            .on_hover_text(format!("{}\n\nHash {}", entry.files.join("\n"), entry.hash));
+                        ui.add_space(4.0);
+                    }
+                });
+            });
+
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(index) = resend {
+            let entry = &self.history[index];
+            let peer = entry.peer.clone();
+            let files = entry.paths.iter().cloned().map(FileHandle::from).collect();
+            self.start_send(peer, files);
+        }
+
+        if !open {
+            self.history_open = false;
+        }
+    }
+}
@@ -1,29 +1,50 @@
-use std::path::PathBuf;
+use std::{collections::BTreeMap, path::PathBuf};
 
 use anyhow::anyhow;
 use eframe::CreationContext;
-use egui::{ahash::{HashSet, HashSetExt}, vec2, Align2, Color32, CornerRadius, Id, LayerId, ProgressBar, RichText, Stroke, Vec2, Widget};
+use egui::{ahash::{HashSet, HashSetExt}, vec2, Align2, Color32, CornerRadius, Id, LayerId, RichText, Stroke};
 use egui_toast::{ToastKind, Toasts};
 use rfd::FileHandle;
-use shared::{app_events::AppEvent, app_state::AppState, network::Network, ui_events::UIEvent, websocket_messages::WebSocketMessage};
+use shared::{app_events::AppEvent, app_state::AppState, history::HistoryEntry, identity, network::Network, settings::Settings, transfer::TransferId, ui_events::UIEvent, websocket_messages::{Offer, DEFAULT_ROOM}};
 
+mod history;
+mod offer;
+mod pending;
+mod settings;
 mod toast;
+mod transfers;
+
+use transfers::Transfer;
 
 pub struct UI<N> {
     network: N,
     app_state: AppState,
-    nickname: String,
+    room: String,
+    room_input: String,
+    /// nickname we last asked the server for, a session resumed after a rename still has the
+    /// old one
+    joined_as: String,
     users: HashSet<String>,
+    offers: Vec<Offer>,
+    /// downloads that were interrupted and can be resumed
+    pending: Vec<Offer>,
+    /// finished transfers, oldest first
+    history: Vec<HistoryEntry>,
+    history_open: bool,
+    history_search: String,
+    settings: Settings,
+    /// copy being edited while the settings window is open
+    settings_draft: Option<Settings>,
     toasts: Toasts,
     files: Vec<rfd::FileHandle>,
     download_dir: PathBuf,
-    is_downloading: bool,
-    is_importing: bool,
-    progress: f32,
+    /// every transfer started this session, in the order they were started
+    transfers: BTreeMap<TransferId, Transfer>,
 }
 
 impl<N: Network> UI<N> {
-    pub fn new(cc: &CreationContext, nickname: String, download_dir: PathBuf, network: N) -> Self {
+    /// Registers as `settings.nickname`, which has to be picked by then.
+    pub fn new(cc: &CreationContext, download_dir: PathBuf, settings: Settings, network: N) -> Self {
         egui_material_icons::initialize(&cc.egui_ctx);
 
         let toasts = Toasts::new()
@@ -34,27 +55,22 @@ impl<N: Network> UI<N> {
             app_state: AppState::Connecting,
             files: Vec::new(),
             users: HashSet::new(),
-            is_downloading: false,
-            is_importing: false,
-            progress: 0.,
-            nickname,
+            offers: Vec::new(),
+            pending: Vec::new(),
+            history: Vec::new(),
+            history_open: false,
+            history_search: String::new(),
+            settings,
+            settings_draft: None,
+            transfers: BTreeMap::new(),
+            room: DEFAULT_ROOM.to_string(),
+            room_input: DEFAULT_ROOM.to_string(),
+            joined_as: String::new(),
             download_dir,
             network,
             toasts,
         }
     }
-
-    fn cleanup(&mut self) {
-        let temp_dir = self.download_dir.join(format!("fling-{}", self.nickname));
-        if let Err(e) = std::fs::remove_dir_all(&temp_dir) {
-            self.network
-                .send(AppEvent::FatalError(anyhow!(e).context(format!(
-                    "Failed to delete temp dir {:?}, be sure to delete it yourself",
-                    temp_dir
-                ))));
-    
-        }
-    }
 }
 
 impl<N: Network> eframe::App for UI<N> {
@@ -82,7 +98,39 @@ impl<N: Network> eframe::App for UI<N> {
             .show(ctx, |ui| {
                 ui.horizontal(|ui| {
                     ui.label(online_icon.clone());
-                    ui.label(RichText::new(&self.nickname).strong().size(14.0));
+                    ui.label(RichText::new(&self.settings.nickname).strong().size(14.0));
+                    let endpoint_id = self.network.endpoint_id();
+                    ui.label(RichText::new(identity::fingerprint(&endpoint_id)).color(text_dim).monospace().size(11.0))
+                        .on_hover_text(format!("Your device's id, others see it next to your name\n{endpoint_id}"));
+
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        let settings_icon = egui_material_icons::icon_text(egui_material_icons::icons::ICON_SETTINGS).size(16.0);
+                        if ui.small_button(settings_icon).on_hover_text("Settings").clicked() {
+                            self.settings_draft = Some(self.settings.clone());
+                        }
+                        let history_icon = egui_material_icons::icon_text(egui_material_icons::icons::ICON_HISTORY).size(16.0);
+                        if ui.small_button(history_icon).on_hover_text("History").clicked() {
+                            self.history_open = !self.history_open;
+                        }
+
+                        if matches!(self.app_state, AppState::Ready) {
+                            let join_clicked = ui.small_button("Join").clicked();
+                            let input = ui.add(
+                                egui::TextEdit::singleline(&mut self.room_input)
+                                    .desired_width(100.0)
+                                    .hint_text(DEFAULT_ROOM),
+                            );
+                            ui.label(RichText::new("Room").color(text_dim).size(12.0));
+
+                            let submitted = input.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+                            let room = self.room_input.trim();
+                            if (join_clicked || submitted) && !room.is_empty() && room != self.room {
+                                self.room = room.to_string();
+                                self.users.clear();
+                                self.app_state = AppState::PublishUser;
+                            }
+                        }
+                    });
                 });
             });
 
@@ -96,29 +144,76 @@ impl<N: Network> eframe::App for UI<N> {
                         AppEvent::RegisterSuccess(current_users) => {
                             let users = HashSet::from_iter(current_users);
                             self.users = users;
-                            self.app_state = AppState::Ready;
+                            // renamed while reconnecting, join again under the new name
+                            self.app_state = if self.joined_as == self.settings.nickname {
+                                AppState::Ready
+                            } else {
+                                AppState::PublishUser
+                            };
+                        }
+                        AppEvent::RegisterRejected(reason) => {
+                            self.app_state = AppState::NicknameRejected {
+                                reason,
+                                input: self.settings.nickname.clone(),
+                            };
                         }
                         AppEvent::AddNewUser(nickname) => {
-                            if nickname != self.nickname {
+                            if nickname != self.settings.nickname {
                                 self.users.insert(nickname);
                             }
                         }
                         AppEvent::RemoveUser(nickname) => {
                             self.users.remove(&nickname);
                         }
+                        AppEvent::Reconnecting(attempt) => {
+                            self.app_state = AppState::Reconnecting { attempt };
+                        }
+                        AppEvent::ServerShuttingDown(retry_after) => {
+                            self.show_toast(
+                                format!("Server is restarting, back in about {retry_after}s"),
+                                ToastKind::Info,
+                            );
+                        }
                         AppEvent::ReceivedFile(files) => {
                             self.files.extend(files);
                         }
-                        AppEvent::UpdateProgressValue(value) => {
-                            self.progress = value;
+                        AppEvent::TransferUpdate(id, state) => self.update_transfer(id, state),
+                        AppEvent::TransferProgress(id, done, total) => {
+                            let now = ctx.input(|i| i.time);
+                            self.update_transfer_progress(id, done, total, now);
+                        }
+                        AppEvent::TransferDetails(id, hash, paths) => self.set_transfer_details(id, hash, paths),
+                        AppEvent::UploadProgress(id, _, done, total) => {
+                            // a send only ever has the one recipient fetching it
+                            let now = ctx.input(|i| i.time);
+                            self.update_transfer_progress(id, done, total, now);
                         }
-                        AppEvent::ImportStart => self.is_importing = true,
-                        AppEvent::ImportDone => self.is_importing = false,
-                        AppEvent::DownloadStart => self.is_downloading = true,
-                        AppEvent::DownloadFile(ticket) => {
-                            self.network.send_ws(UIEvent::DownloadFile(ticket)).ok();
+                        AppEvent::IncomingOffer(offer) => {
+                            self.offers.push(offer);
+                        }
+                        AppEvent::Offered(id, nonce) => self.set_transfer_nonce(id, nonce),
+                        AppEvent::OfferAccepted(nonce, recipient, endpoint_id) => {
+                            self.answer_offer(nonce, Some(endpoint_id));
+                            self.show_toast(format!("{recipient} accepted your file(s)"), ToastKind::Success);
+                        }
+                        AppEvent::OfferDeclined(nonce, recipient) => {
+                            self.answer_offer(nonce, None);
+                            self.show_toast(format!("{recipient} declined your file(s)"), ToastKind::Warning);
+                        }
+                        AppEvent::Delivered(nonce, recipient) => {
+                            self.confirm_delivery(nonce);
+                            self.show_toast(format!("{recipient} received your file(s)"), ToastKind::Success);
+                        }
+                        AppEvent::PendingDownloads(pending) => {
+                            self.pending = pending;
+                        }
+                        AppEvent::History(entries) => self.history.extend(entries),
+                        AppEvent::EntriesRejected(entries) => {
+                            self.show_toast(
+                                format!("Some files weren't saved:\n{}", entries.join("\n")),
+                                ToastKind::Warning,
+                            );
                         }
-                        AppEvent::DownloadDone => self.is_downloading = false,
                         AppEvent::FatalError(e) => {
                             self.show_toast(format!("{e:#}"), ToastKind::Error);
                         }
@@ -134,8 +229,24 @@ impl<N: Network> eframe::App for UI<N> {
                             ui.label(RichText::new("Connecting...").color(text_dim).size(14.0));
                         });
                     } 
+                    AppState::Reconnecting { attempt } => {
+                        let attempt = *attempt;
+                        ui.vertical_centered(|ui| {
+                            ui.add_space(ui.available_height() / 3.0);
+                            ui.add(egui::Spinner::new().size(32.0).color(accent_color));
+                            ui.add_space(12.0);
+                            ui.label(RichText::new("Connection lost, reconnecting...").color(text_dim).size(14.0));
+                            if attempt > 0 {
+                                ui.label(RichText::new(format!("Attempt {attempt}")).color(text_dim).size(12.0));
+                            }
+                        });
+                    }
                     AppState::PublishUser => {
-                        if let Err(e) = self.network.send_ws(UIEvent::Register(self.nickname.clone())) {
+                        self.joined_as = self.settings.nickname.clone();
+                        if let Err(e) = self.network.send_ws(UIEvent::JoinRoom {
+                            room: self.room.clone(),
+                            nickname: self.settings.nickname.clone(),
+                        }) {
                             self.network
                                 .send(AppEvent::FatalError(
                                     anyhow!(e).context("Register send failed"),
@@ -151,6 +262,33 @@ impl<N: Network> eframe::App for UI<N> {
                             ui.label(RichText::new("Registering...").color(text_dim).size(14.0));
                         });
                     }
+                    AppState::NicknameRejected { reason, input } => {
+                        let mut retry: Option<String> = None;
+                        ui.vertical_centered(|ui| {
+                            ui.add_space(ui.available_height() / 3.0);
+                            ui.label(RichText::new(reason.as_str()).color(Color32::from_rgb(200, 80, 80)).size(14.0));
+                            ui.add_space(12.0);
+                            let response = ui.add(
+                                egui::TextEdit::singleline(input)
+                                    .desired_width(200.0)
+                                    .hint_text("Choose another nickname"),
+                            );
+                            let submitted = response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+                            ui.add_space(8.0);
+                            let btn = egui::Button::new(RichText::new("Register").color(Color32::WHITE).size(13.0))
+                                .fill(accent_color)
+                                .corner_radius(6.0);
+                            if (ui.add(btn).clicked() || submitted) && !input.trim().is_empty() {
+                                retry = Some(input.trim().to_string());
+                            }
+                        });
+
+                        if let Some(nickname) = retry {
+                            self.settings.nickname = nickname;
+                            self.network.send_ws(UIEvent::UpdateSettings(self.settings.clone())).ok();
+                            self.app_state = AppState::PublishUser;
+                        }
+                    }
                     AppState::Ready => {
                         // file drop zone
                         egui::Frame::new()
@@ -170,7 +308,12 @@ impl<N: Network> eframe::App for UI<N> {
                                         }
 
                                         #[cfg(not(target_arch = "wasm32"))]
-                                        ui.label(RichText::new("or drag and drop").color(text_dim).size(12.0));
+                                        {
+                                            if ui.link(RichText::new("or a folder").color(accent_color).size(12.0)).clicked() {
+                                                self.network.open_folder_dialog();
+                                            }
+                                            ui.label(RichText::new("or drag and drop").color(text_dim).size(12.0));
+                                        }
                                     } else {
                                         ui.add_space(8.0);
                                         let mut file_to_remove: Option<usize> = None;
@@ -179,10 +322,13 @@ impl<N: Network> eframe::App for UI<N> {
                                             let file = file.inner();
 
                                             #[cfg(target_arch = "wasm32")]
-                                            let file_name = file.name();
+                                            let (file_name, icon) = (file.name(), "📄");
 
                                             #[cfg(not(target_arch = "wasm32"))]
-                                            let file_name = file.file_name().unwrap_or_default().to_string_lossy().to_string();
+                                            let (file_name, icon) = (
+                                                file.file_name().unwrap_or_default().to_string_lossy().to_string(),
+                                                if file.is_dir() { "📁" } else { "📄" },
+                                            );
 
                                             egui::Frame::default()
                                                 .corner_radius(8)
@@ -192,7 +338,7 @@ impl<N: Network> eframe::App for UI<N> {
                                                     ui.set_min_width(120.0);
                                                     ui.vertical(|ui| {
                                                         ui.horizontal(|ui| {
-                                                            ui.label(RichText::new("📄").size(20.0));
+                                                            ui.label(RichText::new(icon).size(20.0));
                                                             ui.with_layout(egui::Layout::right_to_left(egui::Align::TOP), |ui| {
                                                                 if ui.small_button(
                                                                     RichText::new("×").size(16.0).color(Color32::from_rgb(200, 80, 80))
@@ -220,6 +366,13 @@ impl<N: Network> eframe::App for UI<N> {
                                             if ui.link(RichText::new("+ Add more files").color(accent_color).size(12.0)).clicked() {
                                                 self.network.open_file_dialog();
                                             }
+                                            #[cfg(not(target_arch = "wasm32"))]
+                                            {
+                                                ui.label(RichText::new("•").color(text_dim).size(12.0));
+                                                if ui.link(RichText::new("+ Add folder").color(accent_color).size(12.0)).clicked() {
+                                                    self.network.open_folder_dialog();
+                                                }
+                                            }
                                             ui.label(RichText::new("•").color(text_dim).size(12.0));
                                             if ui.link(RichText::new("Clear all").color(accent_color).size(12.0)).clicked() {
                                                 self.files.clear();
@@ -231,6 +384,8 @@ impl<N: Network> eframe::App for UI<N> {
 
                         ui.add_space(16.0);
 
+                        self.show_pending_downloads(ui);
+
                         // online users section
                         ui.label(RichText::new("Online").color(text_dim).size(12.0));
                         ui.add_space(4.0);
@@ -246,6 +401,7 @@ impl<N: Network> eframe::App for UI<N> {
                                     });
                                 });
                         } else {
+                            let mut send_to = None;
                             egui::ScrollArea::vertical().max_height(200.0).show(ui, |ui| {
                                 for user in &self.users {
                                     egui::Frame::new()
@@ -266,15 +422,7 @@ impl<N: Network> eframe::App for UI<N> {
                                                         .fill(if has_file { accent_color } else { bg_dark })
                                                         .corner_radius(6.0);
                                                     if ui.add_enabled(has_file, btn).clicked() {
-                                                        if let Err(e) = self.network.send_ws(UIEvent::PrepareFile {
-                                                            recipient: user.clone(),
-                                                            files: self.files.clone(),
-                                                        }) {
-                                                            self.network
-                                                                .send(AppEvent::FatalError(
-                                                                    anyhow!(e).context("failed to send websocket msg"),
-                                                                ));
-                                                        }
+                                                        send_to = Some(user.clone());
                                                     }
                                                 });
                                             });
@@ -282,6 +430,9 @@ impl<N: Network> eframe::App for UI<N> {
                                     ui.add_space(4.0);
                                 }
                             });
+                            if let Some(recipient) = send_to {
+                                self.start_send(recipient, self.files.clone());
+                            }
                         }
 
                         #[cfg(not(target_arch = "wasm32"))]    
@@ -302,46 +453,15 @@ impl<N: Network> eframe::App for UI<N> {
                     }
                 }
 
-                // importing indicator
-                if self.is_importing {
-                    egui::TopBottomPanel::bottom("import_panel")
-                        .frame(egui::Frame::new()
-                            .fill(Color32::from_rgba_unmultiplied(40, 40, 40, 240))
-                            .inner_margin(12.0))
-                        .show(ctx, |ui| {
-                            ui.horizontal(|ui| {
-                                ui.spinner();
-                                ui.add_space(8.0);
-                                ui.label(RichText::new("Preparing files...").color(text_dim).size(11.0));
-                            });
-                        });
-                }
-
-                // progress bar
-                if (self.progress > 0.0 && self.progress < 1.0) || self.is_downloading {
-                    egui::TopBottomPanel::bottom("progress_panel")
-                        .frame(egui::Frame::new()
-                            .fill(Color32::from_rgba_unmultiplied(40, 40, 40, 240))
-                            .inner_margin(12.0))
-                        .show(ctx, |ui| {
-                            ui.vertical(|ui| {
-                                ui.label(RichText::new("Downloading file(s)...").color(text_dim).size(11.0));
-                                ui.add_space(4.0);
-                                ProgressBar::new(self.progress)
-                                    .show_percentage()
-                                    .ui(ui);
-                            });
-                        });
-                }
-
+                self.record_finished_transfers(ctx.input(|i| i.time));
+                self.show_transfers_panel(ctx);
+                self.show_offer_dialog(ctx);
+                self.show_settings_window(ctx);
+                self.show_history_window(ctx);
                 self.toasts.show(ctx);
                 ctx.request_repaint();
             });
     }
-
-    fn on_exit(&mut self, _gl: Option<&eframe::glow::Context>) {
-        self.cleanup();
-    }
 }
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -0,0 +1,81 @@
+use egui::{Color32, RichText};
+use shared::{identity, ui_events::UIEvent, units::format_size};
+
+use crate::{Network, UI};
+
+impl<N: Network> UI<N> {
+    /// Shows the oldest pending offer and lets the user accept or decline it.
+    pub fn show_offer_dialog(&mut self, ctx: &egui::Context) {
+        let Some(offer) = self.offers.first() else {
+            return;
+        };
+
+        // refused here rather than failing once the download starts
+        let problem = match self.settings.max_download_size {
+            Some(max) if offer.size > max => {
+                Some(format!("Over your {} download limit", format_size(max)))
+            }
+            _ => self.network.check_space(offer.size).err().map(|e| format!("{e:#}")),
+        };
+
+        let mut accepted: Option<bool> = None;
+        egui::Window::new(format!(
+            "Incoming files from {} ({})",
+            offer.sender,
+            identity::fingerprint(&offer.sender_id)
+        ))
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, (0.0, 0.0))
+            .show(ctx, |ui| {
+                ui.label(
+                    RichText::new(format!(
+                        "{} file(s), {} in total",
+                        offer.files.len(),
+                        format_size(offer.size)
+                    ))
+                    .size(13.0),
+                );
+                ui.add_space(4.0);
+
+                egui::ScrollArea::vertical().max_height(150.0).show(ui, |ui| {
+                    for name in &offer.files {
+                        ui.label(RichText::new(format!("📄 {name}")).size(12.0));
+                    }
+                });
+
+                if let Some(problem) = &problem {
+                    ui.add_space(4.0);
+                    ui.label(RichText::new(problem).color(Color32::from_rgb(230, 160, 60)).size(12.0));
+                }
+
+                ui.add_space(8.0);
+                ui.horizontal(|ui| {
+                    let accept = egui::Button::new(RichText::new("Accept").color(Color32::WHITE).size(12.0))
+                        .fill(Color32::from_rgb(79, 140, 255))
+                        .corner_radius(6.0);
+                    if ui.add_enabled(problem.is_none(), accept).clicked() {
+                        accepted = Some(true);
+                    }
+                    if ui.button(RichText::new("Decline").size(12.0)).clicked() {
+                        accepted = Some(false);
+                    }
+                });
+            });
+
+        let Some(accepted) = accepted else {
+            return;
+        };
+        let offer = self.offers.remove(0);
+
+        self.network
+            .send_ws(UIEvent::RespondOffer {
+                offer_id: offer.id,
+                accepted,
+            })
+            .ok();
+        if accepted {
+            self.start_download(offer);
+        }
+    }
+}
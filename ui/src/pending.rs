@@ -0,0 +1,61 @@
+use egui::{Color32, RichText};
+use shared::{ui_events::UIEvent, units::format_size};
+
+use crate::{Network, UI};
+
+impl<N: Network> UI<N> {
+    /// Lists downloads that were interrupted, letting the user pick them up where they stopped.
+    pub fn show_pending_downloads(&mut self, ui: &mut egui::Ui) {
+        if self.pending.is_empty() {
+            return;
+        }
+
+        let text_dim = Color32::from_rgb(140, 140, 140);
+        ui.label(RichText::new("Unfinished downloads").color(text_dim).size(12.0));
+        ui.add_space(4.0);
+
+        let mut resume = None;
+        let mut discard = None;
+        for (index, offer) in self.pending.iter().enumerate() {
+            egui::Frame::new()
+                .fill(Color32::from_rgb(40, 40, 40))
+                .corner_radius(8.0)
+                .inner_margin(12.0)
+                .show(ui, |ui| {
+                    ui.horizontal(|ui| {
+                        ui.label(
+                            RichText::new(format!(
+                                "{} file(s) from {}, {}",
+                                offer.files.len(),
+                                offer.sender,
+                                format_size(offer.size)
+                            ))
+                            .size(13.0),
+                        );
+                        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                            if ui.small_button(RichText::new("Discard").size(12.0)).clicked() {
+                                discard = Some(index);
+                            }
+                            let btn = egui::Button::new(RichText::new("Resume").color(Color32::WHITE).size(12.0))
+                                .fill(Color32::from_rgb(79, 140, 255))
+                                .corner_radius(6.0);
+                            if ui.add_enabled(!self.is_downloading(&offer.ticket), btn).clicked() {
+                                resume = Some(index);
+                            }
+                        });
+                    });
+                });
+            ui.add_space(4.0);
+        }
+
+        if let Some(index) = resume {
+            self.start_download(self.pending[index].clone());
+        }
+        if let Some(index) = discard {
+            let offer = self.pending.remove(index);
+            self.network.send_ws(UIEvent::DiscardDownload(offer.ticket)).ok();
+        }
+
+        ui.add_space(12.0);
+    }
+}
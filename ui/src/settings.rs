@@ -0,0 +1,102 @@
+use egui::{Color32, RichText};
+#[cfg(not(target_arch = "wasm32"))]
+use shared::settings::ConflictPolicy;
+use shared::{app_state::AppState, ui_events::UIEvent};
+
+use crate::{Network, UI};
+
+impl<N: Network> UI<N> {
+    /// Edits a copy of the settings and hands it to the network layer once saved.
+    pub fn show_settings_window(&mut self, ctx: &egui::Context) {
+        let Some(draft) = &mut self.settings_draft else {
+            return;
+        };
+
+        let mut open = true;
+        let mut save = false;
+        egui::Window::new("Settings")
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, (0.0, 0.0))
+            .show(ctx, |ui| {
+                ui.label(RichText::new("Name").size(12.0));
+                ui.add(
+                    egui::TextEdit::singleline(&mut draft.nickname)
+                        .desired_width(260.0)
+                        .hint_text("How others see you"),
+                );
+
+                ui.add_space(8.0);
+                ui.label(RichText::new("Server").size(12.0));
+                ui.add(
+                    egui::TextEdit::singleline(&mut draft.server_url)
+                        .desired_width(260.0)
+                        .hint_text("wss://example.com/ws"),
+                );
+
+                // the browser doesn't save received files anywhere
+                #[cfg(not(target_arch = "wasm32"))]
+                {
+                    ui.add_space(8.0);
+                    ui.label(RichText::new("When a received file already exists").size(12.0));
+                    ui.horizontal(|ui| {
+                        for policy in ConflictPolicy::ALL {
+                            let label = match policy {
+                                ConflictPolicy::Rename => "Keep both",
+                                ConflictPolicy::Skip => "Skip",
+                                ConflictPolicy::Overwrite => "Overwrite",
+                            };
+                            ui.radio_value(&mut draft.conflict_policy, policy, RichText::new(label).size(12.0));
+                        }
+                    });
+
+                    ui.add_space(8.0);
+                    ui.horizontal(|ui| {
+                        ui.label(RichText::new("Transfers at once").size(12.0));
+                        ui.add(egui::DragValue::new(&mut draft.max_transfers).range(1..=8));
+                    });
+
+                    const GB: f64 = 1024.0 * 1024.0 * 1024.0;
+                    ui.horizontal(|ui| {
+                        let mut limited = draft.max_download_size.is_some();
+                        if ui.checkbox(&mut limited, RichText::new("Limit download size").size(12.0)).changed() {
+                            draft.max_download_size = limited.then_some(5 * GB as u64);
+                        }
+                        if let Some(max) = &mut draft.max_download_size {
+                            let mut gb = *max as f64 / GB;
+                            let input = egui::DragValue::new(&mut gb).range(0.1..=f64::MAX).speed(0.5).suffix(" GB");
+                            if ui.add(input).changed() {
+                                *max = (gb * GB) as u64;
+                            }
+                        }
+                    });
+                }
+
+                ui.add_space(8.0);
+                let btn = egui::Button::new(RichText::new("Save").color(Color32::WHITE).size(12.0))
+                    .fill(Color32::from_rgb(79, 140, 255))
+                    .corner_radius(6.0);
+                save = ui.add(btn).clicked();
+            });
+
+        if save && let Some(mut settings) = self.settings_draft.take() {
+            settings.nickname = settings.nickname.trim().to_string();
+            if settings.nickname.is_empty() {
+                settings.nickname = self.settings.nickname.clone();
+            }
+            if settings.server_url != self.settings.server_url {
+                self.users.clear();
+            }
+            // joining again under the new name, the server drops the old one. Until we're back
+            // in the room, that waits for the server to answer
+            if settings.nickname != self.settings.nickname && matches!(self.app_state, AppState::Ready) {
+                self.app_state = AppState::PublishUser;
+            }
+            self.settings = settings.clone();
+            self.network.send_ws(UIEvent::UpdateSettings(settings)).ok();
+        } else if !open {
+            self.settings_draft = None;
+        }
+    }
+}
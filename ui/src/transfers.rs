@@ -0,0 +1,310 @@
+use std::path::PathBuf;
+
+use egui::{Color32, ProgressBar, RichText, Widget};
+use egui_toast::ToastKind;
+use rfd::FileHandle;
+use shared::{
+    history::HistoryEntry,
+    transfer::{next_transfer_id, Direction, Throughput, TransferId, TransferState},
+    ui_events::UIEvent,
+    units::{format_duration, format_size, format_speed},
+    websocket_messages::Offer,
+};
+
+use crate::{Network, UI};
+
+/// A transfer as the UI sees it, started here and kept up to date by the network layer.
+pub struct Transfer {
+    pub direction: Direction,
+    /// who it's going to or coming from
+    pub peer: String,
+    /// names of the picked files for a send, of the offered ones for a receive
+    pub files: Vec<String>,
+    /// the offer's ticket for receives, to tell whether a pending download is already running
+    pub ticket: Option<String>,
+    /// the nonce a send's offer was signed with, the recipient's answers carry it
+    pub nonce: Option<u64>,
+    pub state: TransferState,
+    pub done: u64,
+    pub total: u64,
+    /// speed of the current stage, kept once it's done to show what it averaged
+    pub throughput: Throughput,
+    /// collection hash and files on disk, once the network layer knows them
+    pub hash: String,
+    pub paths: Vec<PathBuf>,
+    /// UI clock time it left the queue at
+    pub started: Option<f64>,
+    /// whether it's been saved to the history
+    pub recorded: bool,
+}
+
+impl Transfer {
+    /// Whether there's still a task running for it that can be cancelled.
+    fn is_running(&self) -> bool {
+        matches!(
+            self.state,
+            TransferState::Queued
+                | TransferState::Importing
+                | TransferState::Downloading
+                | TransferState::Exporting
+        )
+    }
+
+    /// Whether it's running or has offered something that can still be fetched.
+    fn can_cancel(&self) -> bool {
+        self.is_running() || matches!(self.state, TransferState::Offering | TransferState::Uploading)
+    }
+}
+
+impl<N: Network> UI<N> {
+    pub fn start_send(&mut self, recipient: String, files: Vec<FileHandle>) {
+        let id = next_transfer_id();
+        self.transfers.insert(
+            id,
+            Transfer {
+                direction: Direction::Send,
+                peer: recipient.clone(),
+                files: files.iter().map(|f| f.file_name()).collect(),
+                ticket: None,
+                nonce: None,
+                state: TransferState::Queued,
+                done: 0,
+                total: 0,
+                throughput: Throughput::default(),
+                hash: String::new(),
+                paths: Vec::new(),
+                started: None,
+                recorded: false,
+            },
+        );
+        self.network
+            .send_ws(UIEvent::PrepareFile { id, recipient, files })
+            .ok();
+    }
+
+    /// Downloads `offer`, the native side picks up where it stopped if it's a pending one.
+    pub fn start_download(&mut self, offer: Offer) {
+        let id = next_transfer_id();
+        self.transfers.insert(
+            id,
+            Transfer {
+                direction: Direction::Receive,
+                peer: offer.sender.clone(),
+                files: offer.files.clone(),
+                ticket: Some(offer.ticket.clone()),
+                nonce: None,
+                state: TransferState::Queued,
+                done: 0,
+                total: offer.size,
+                throughput: Throughput::default(),
+                hash: String::new(),
+                paths: Vec::new(),
+                started: None,
+                recorded: false,
+            },
+        );
+        self.network.send_ws(UIEvent::DownloadFile { id, offer }).ok();
+    }
+
+    pub fn is_downloading(&self, ticket: &str) -> bool {
+        self.transfers
+            .values()
+            .any(|t| t.is_running() && t.ticket.as_deref() == Some(ticket))
+    }
+
+    pub fn update_transfer(&mut self, id: TransferId, state: TransferState) {
+        let Some(transfer) = self.transfers.get_mut(&id) else {
+            return;
+        };
+        if matches!(state, TransferState::Importing | TransferState::Downloading) {
+            transfer.throughput = Throughput::default();
+        }
+        transfer.state = state.clone();
+
+        let peer = transfer.peer.clone();
+        match (transfer.direction, state) {
+            (Direction::Receive, TransferState::Done) => {
+                self.show_toast(format!("Received file(s) from {peer}"), ToastKind::Success);
+            }
+            (_, TransferState::Failed(e)) => self.show_toast(e, ToastKind::Error),
+            (_, TransferState::Cancelled) => self.show_toast("Transfer cancelled", ToastKind::Info),
+            _ => {}
+        }
+    }
+
+    /// `now` is the UI clock in seconds, only used to work out the speed.
+    pub fn update_transfer_progress(&mut self, id: TransferId, done: u64, total: u64, now: f64) {
+        if let Some(transfer) = self.transfers.get_mut(&id) {
+            transfer.done = done;
+            transfer.total = total;
+            transfer.throughput.update(done, now);
+        }
+    }
+
+    pub fn set_transfer_nonce(&mut self, id: TransferId, nonce: u64) {
+        if let Some(transfer) = self.transfers.get_mut(&id) {
+            transfer.nonce = Some(nonce);
+        }
+    }
+
+    pub fn set_transfer_details(&mut self, id: TransferId, hash: String, paths: Vec<PathBuf>) {
+        if let Some(transfer) = self.transfers.get_mut(&id) {
+            transfer.hash = hash;
+            transfer.paths = paths;
+        }
+    }
+
+    /// Times transfers from when they leave the queue and saves the ones that just finished to
+    /// the history, whichever way they got there. `now` is the UI clock in seconds.
+    pub fn record_finished_transfers(&mut self, now: f64) {
+        for transfer in self.transfers.values_mut() {
+            if transfer.started.is_none() && transfer.state != TransferState::Queued {
+                transfer.started = Some(now);
+            }
+            if transfer.recorded || !transfer.state.is_finished() {
+                continue;
+            }
+            transfer.recorded = true;
+
+            let entry = HistoryEntry {
+                direction: transfer.direction,
+                peer: transfer.peer.clone(),
+                files: transfer.files.clone(),
+                size: transfer.total,
+                hash: transfer.hash.clone(),
+                finished_at: 0,
+                duration: now - transfer.started.unwrap_or(now),
+                outcome: transfer.state.clone(),
+                paths: transfer.paths.clone(),
+            };
+            self.network.send_ws(UIEvent::RecordHistory(entry)).ok();
+        }
+    }
+
+    /// Settles the send whose offer was signed with `nonce`, if it's still waiting for an
+    /// answer. `accepted` is the endpoint the recipient fetches from, the only one that gets to,
+    /// or `None` if it declined.
+    pub fn answer_offer(&mut self, nonce: u64, accepted: Option<String>) {
+        let offering = self.transfers.iter_mut().find(|(_, t)| {
+            t.direction == Direction::Send
+                && t.nonce == Some(nonce)
+                && t.state == TransferState::Offering
+        });
+        if let Some((&id, transfer)) = offering {
+            transfer.state = match accepted {
+                Some(endpoint_id) => {
+                    self.network
+                        .send_ws(UIEvent::GrantAccess { id, endpoint_id })
+                        .ok();
+                    TransferState::Uploading
+                }
+                None => TransferState::Failed(format!("{} declined", transfer.peer)),
+            };
+            transfer.done = 0;
+            transfer.throughput = Throughput::default();
+        }
+    }
+
+    /// Marks the upload of the offer signed with `nonce` as delivered, once the recipient
+    /// confirmed the download.
+    pub fn confirm_delivery(&mut self, nonce: u64) {
+        let uploading = self.transfers.values_mut().find(|t| {
+            t.direction == Direction::Send
+                && t.nonce == Some(nonce)
+                && t.state == TransferState::Uploading
+        });
+        if let Some(transfer) = uploading {
+            transfer.state = TransferState::Delivered;
+            transfer.done = transfer.total;
+        }
+    }
+
+    /// Lists every transfer at the bottom of the window, each with its own progress.
+    pub fn show_transfers_panel(&mut self, ctx: &egui::Context) {
+        if self.transfers.is_empty() {
+            return;
+        }
+
+        let text_dim = Color32::from_rgb(140, 140, 140);
+        let mut cancel = None;
+        let mut clear = false;
+        egui::TopBottomPanel::bottom("transfers_panel")
+            .frame(egui::Frame::new()
+                .fill(Color32::from_rgba_unmultiplied(40, 40, 40, 240))
+                .inner_margin(12.0))
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label(RichText::new("Transfers").color(text_dim).size(12.0));
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        let finished = self.transfers.values().any(|t| t.state.is_finished());
+                        if finished && ui.small_button(RichText::new("Clear finished").size(11.0)).clicked() {
+                            clear = true;
+                        }
+                    });
+                });
+
+                egui::ScrollArea::vertical().max_height(160.0).show(ui, |ui| {
+                    for (id, transfer) in self.transfers.iter().rev() {
+                        let label = match transfer.direction {
+                            Direction::Send => format!("{} file(s) to {}", transfer.files.len(), transfer.peer),
+                            Direction::Receive => format!("{} file(s) from {}", transfer.files.len(), transfer.peer),
+                        };
+                        ui.horizontal(|ui| {
+                            ui.label(RichText::new(label).size(12.0));
+                            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                                if transfer.can_cancel() && ui.small_button(RichText::new("Cancel").size(11.0)).clicked() {
+                                    cancel = Some(*id);
+                                }
+                                ui.label(RichText::new(transfer.state.to_string()).color(text_dim).size(11.0));
+                            });
+                        });
+
+                        let throughput = &transfer.throughput;
+                        let measured = matches!(
+                            transfer.state,
+                            TransferState::Importing | TransferState::Uploading | TransferState::Downloading
+                        );
+                        if measured && transfer.total > 0 {
+                            let value = transfer.done as f32 / transfer.total as f32;
+                            ProgressBar::new(value)
+                                .text(format!("{} / {}", format_size(transfer.done), format_size(transfer.total)))
+                                .ui(ui);
+
+                            if throughput.current() > 0.0 {
+                                let mut speed = format!(
+                                    "{}, avg {}",
+                                    format_speed(throughput.current()),
+                                    format_speed(throughput.smoothed())
+                                );
+                                if let Some(eta) = throughput.eta(transfer.total) {
+                                    speed.push_str(&format!(", {} left", format_duration(eta)));
+                                }
+                                ui.label(RichText::new(speed).color(text_dim).size(11.0));
+                            }
+                        } else if matches!(transfer.state, TransferState::Done | TransferState::Delivered)
+                            && throughput.average() > 0.0
+                        {
+                            ui.label(
+                                RichText::new(format!(
+                                    "{} in {}, {} on average",
+                                    format_size(transfer.total),
+                                    format_duration(throughput.elapsed()),
+                                    format_speed(throughput.average())
+                                ))
+                                .color(text_dim)
+                                .size(11.0),
+                            );
+                        }
+                        ui.add_space(4.0);
+                    }
+                });
+            });
+
+        if let Some(id) = cancel {
+            self.network.send_ws(UIEvent::CancelTransfer(id)).ok();
+        }
+        if clear {
+            self.transfers.retain(|_, t| !t.state.is_finished());
+        }
+    }
+}